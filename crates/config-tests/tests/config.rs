@@ -0,0 +1,133 @@
+use moxy::{Display, Get, Set};
+
+#[derive(Display, Get, Set)]
+pub struct User {
+    #[moxy(get, set)]
+    pub user_name: String,
+    #[moxy(get(copy))]
+    pub age: u32,
+}
+
+#[derive(Display, Set)]
+#[moxy(
+    display(map, pretty = false, rename_all = "kebab-case"),
+    set(prefix = "put_")
+)]
+pub struct Overridden {
+    #[moxy(set)]
+    pub first_name: String,
+    #[moxy(set("rename"))]
+    pub last_name: String,
+}
+
+#[derive(Display, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SerdeCase {
+    pub user_name: String,
+}
+
+#[derive(Display)]
+#[moxy(display("{} is {}", self.user_name, self.age))]
+pub struct Custom {
+    pub user_name: String,
+    pub age: u32,
+}
+
+#[derive(Display, Get)]
+#[moxy(get(vis = "pub"))]
+pub enum Event {
+    Login {
+        #[moxy(get(copy))]
+        user_id: u32,
+    },
+    Logout,
+}
+
+#[test]
+fn test_display_defaults() {
+    let user = User {
+        user_name: "john".into(),
+        age: 30,
+    };
+
+    assert_eq!(user.to_string(), "userName=john\nage=30");
+}
+
+#[test]
+fn test_display_overrides() {
+    let value = Overridden {
+        first_name: "a".into(),
+        last_name: "b".into(),
+    };
+
+    assert_eq!(value.to_string(), "{ first-name: a, last-name: b }");
+
+    let custom = Custom {
+        user_name: "john".into(),
+        age: 30,
+    };
+
+    assert_eq!(custom.to_string(), "john is 30");
+}
+
+/// a type's own `#[serde(rename_all)]` wins over `moxy.toml`
+#[test]
+fn test_serde_case_overrides_config() {
+    let value = SerdeCase {
+        user_name: "john".into(),
+    };
+
+    assert_eq!(value.to_string(), "user-name=john");
+}
+
+#[derive(Display)]
+pub enum Move {
+    To {
+        x: i32,
+        y: i32,
+    },
+    #[moxy(display(pretty = false))]
+    By {
+        x: i32,
+        y: i32,
+    },
+}
+
+#[test]
+fn test_display_opt_out() {
+    assert_eq!(Move::To { x: 1, y: 2 }.to_string(), "x=1\ny=2");
+    assert_eq!(Move::By { x: 1, y: 2 }.to_string(), "x=1 y=2");
+}
+
+#[test]
+fn test_display_enum_defaults() {
+    assert_eq!(Event::Login { user_id: 7 }.to_string(), "userId=7");
+    assert_eq!(Event::Logout.to_string(), "logout");
+}
+
+#[test]
+fn test_set_prefix() {
+    let mut user = User {
+        user_name: "john".into(),
+        age: 30,
+    };
+
+    user.with_user_name("jane");
+    assert_eq!(user.user_name(), "jane");
+    assert_eq!(user.age(), 30);
+
+    let mut value = Overridden {
+        first_name: "a".into(),
+        last_name: "b".into(),
+    };
+
+    value.put_first_name("c").rename("d");
+    assert_eq!(value.first_name, "c");
+    assert_eq!(value.last_name, "d");
+}
+
+#[test]
+fn test_get_vis() {
+    assert_eq!(Event::Login { user_id: 7 }.user_id(), Some(7));
+    assert_eq!(Event::Logout.user_id(), None);
+}
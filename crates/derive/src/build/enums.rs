@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Field, to_snake_case},
+    params,
+};
+
+use super::structs::StructSyntax;
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let ident = &args.input.ident;
+        let attributes = Attrs::parse(&args.input.attrs)?;
+
+        if let Some(arg) = attributes.setting("build", "buildable")? {
+            return Err(arg
+                .path()
+                .error("`build(buildable)` is only supported on structs"));
+        }
+
+        let defaults = attributes.defaults("build", &["bound"])?;
+        let builders = args
+            .data
+            .variants
+            .iter()
+            .filter(|variant| matches!(variant.fields, syn::Fields::Named(_)))
+            .map(|variant| -> syn::Result<TokenStream> {
+                let variant_ident = &variant.ident;
+                let fields: Vec<_> = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| Field::parse(i, field)?.inherit("build", defaults.as_deref()))
+                    .combine()?;
+
+                let ctor_ident = match self.render_custom_ctor_name(variant)? {
+                    Some(id) => id,
+                    None => self.render_ctor_name(variant_ident)?,
+                };
+
+                StructSyntax.render_builder(
+                    &args.input,
+                    &format_ident!("{}{}Builder", ident, variant_ident),
+                    &ctor_ident,
+                    &quote!(#ident::#variant_ident),
+                    fields,
+                    false,
+                )
+            })
+            .combine()?;
+
+        Ok(quote! {
+            #(#builders)*
+        })
+    }
+}
+
+impl EnumSyntax {
+    /// the snake_case constructor of a variant, e.g. `Source::file()`,
+    /// made raw when it is a keyword, e.g. `Token::r#type()`
+    fn render_ctor_name(&self, variant_ident: &syn::Ident) -> syn::Result<syn::Ident> {
+        let name = to_snake_case(&variant_ident.unraw().to_string());
+
+        if syn::parse_str::<syn::Ident>(&name).is_ok() {
+            return Ok(format_ident!("{}", name, span = variant_ident.span()));
+        }
+
+        if matches!(name.as_str(), "self" | "super" | "crate") {
+            return Err(variant_ident.error(&format!(
+                "`{name}` cannot be used as a constructor name, rename it with `#[moxy(build(\"...\"))]`"
+            )));
+        }
+
+        Ok(syn::Ident::new_raw(&name, variant_ident.span()))
+    }
+
+    /// `#[moxy(build("from_file"))]` on a variant renames its constructor
+    fn render_custom_ctor_name(&self, variant: &syn::Variant) -> syn::Result<Option<syn::Ident>> {
+        let build_args = Attrs::parse(&variant.attrs)?.get("build")?;
+        let name = build_args
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .flat_map(|attr| attr.args().iter())
+            .filter(|arg| arg.path().is_ident("__value"))
+            .find_map(|arg| match arg.as_lit() {
+                Some(syn::Lit::Str(s)) => Some(s.clone()),
+                _ => None,
+            });
+
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        syn::parse_str::<syn::Ident>(&name.value())
+            .map(|mut ident| {
+                ident.set_span(name.span());
+                Some(ident)
+            })
+            .map_err(|_| {
+                name.error(&format!(
+                    "`{}` is not a valid constructor name",
+                    name.value()
+                ))
+            })
+    }
+}
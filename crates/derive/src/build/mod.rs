@@ -1,11 +1,21 @@
+mod enums;
 mod structs;
 
-use crate::{Render, params::Params};
+use crate::{Render, core::Schema, params::Params};
+
+pub const SCHEMA: Schema = Schema {
+    key: "build",
+    container: Some(&["__flag", "bound =", "buildable"]),
+    variant: Some(&["__value"]),
+    field: Some(&["__flag", "__value", "skip", "default ="]),
+};
 
 pub fn render(tokens: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
     let args = syn::parse(tokens)?;
+    SCHEMA.validate(&args)?;
 
     match args {
         Params::Struct(v) => structs::StructSyntax.render(v),
+        Params::Enum(v) => enums::EnumSyntax.render(v),
     }
 }
@@ -1,7 +1,12 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use syn::ext::IdentExt;
 
-use crate::{Render, core::Field, params};
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Bounds, Field, unique_param},
+    params,
+};
 
 #[derive(Clone, Default)]
 pub struct StructSyntax;
@@ -11,25 +16,92 @@ impl Render for StructSyntax {
 
     fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
         let ident = &args.input.ident;
-        let build_ident = format_ident!("{}Builder", &ident);
-        let vis = &args.input.vis;
-        let generics = &args.input.generics;
-        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
-        let type_params: Vec<_> = generics.type_params().collect();
-        let type_param_idents: Vec<_> = generics.type_params().map(|tp| &tp.ident).collect();
-        let lifetime_params: Vec<_> = generics.lifetimes().collect();
+        let defaults =
+            Attrs::parse(&args.input.attrs)?.defaults("build", &["bound", "buildable"])?;
         let all_fields: Vec<_> = args
             .data
             .fields
             .iter()
             .enumerate()
-            .map(|(i, field)| Field::parse(i, field))
-            .collect::<syn::Result<Vec<_>>>()?;
+            .map(|(i, field)| Field::parse(i, field)?.inherit("build", defaults.as_deref()))
+            .combine()?;
+
+        self.render_builder(
+            &args.input,
+            &format_ident!("{}Builder", ident),
+            &format_ident!("new"),
+            &quote!(#ident),
+            all_fields,
+            true,
+        )
+    }
+}
 
-        let fields: Vec<_> = all_fields
-            .into_iter()
-            .filter(|field| field.attrs().exists("build"))
+impl StructSyntax {
+    /// render a typestate builder named `build_ident` that constructs
+    /// `target { .. }` and is created by `#ident::#ctor_ident()`.
+    /// tuple fields are stored and set as `_0`, `_1` and so on
+    /// unless renamed with `#[moxy(build("name"))]`.
+    /// fields not marked `#[moxy(build)]` are filled from the struct's
+    /// own `Default` impl when `is_struct`, and from each field type's
+    /// `Default` otherwise (enum variants cannot use struct update syntax)
+    pub fn render_builder(
+        &self,
+        input: &syn::DeriveInput,
+        build_ident: &syn::Ident,
+        ctor_ident: &syn::Ident,
+        target: &TokenStream,
+        all_fields: Vec<Field>,
+        is_struct: bool,
+    ) -> syn::Result<TokenStream> {
+        let ident = &input.ident;
+        let vis = &input.vis;
+        let generics = &input.generics;
+        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+        // the type's own generics come first, as declared for the builder
+        // struct and without defaults for impl blocks, followed by one
+        // typestate `bool` per required field
+        let params: Vec<_> = generics.params.iter().collect();
+        let impl_params: Vec<_> = generics
+            .params
+            .iter()
+            .cloned()
+            .map(|mut param| {
+                match &mut param {
+                    syn::GenericParam::Type(tp) => {
+                        tp.eq_token = None;
+                        tp.default = None;
+                    }
+                    syn::GenericParam::Const(cp) => {
+                        cp.eq_token = None;
+                        cp.default = None;
+                    }
+                    syn::GenericParam::Lifetime(_) => {}
+                }
+                param
+            })
+            .collect();
+        let param_refs: Vec<_> = generics
+            .params
+            .iter()
+            .map(|param| match param {
+                syn::GenericParam::Lifetime(lt) => {
+                    let lifetime = &lt.lifetime;
+                    quote!(#lifetime)
+                }
+                syn::GenericParam::Type(tp) => {
+                    let ident = &tp.ident;
+                    quote!(#ident)
+                }
+                syn::GenericParam::Const(cp) => {
+                    let ident = &cp.ident;
+                    quote!(#ident)
+                }
+            })
             .collect();
+        let (fields, skipped): (Vec<_>, Vec<_>) = all_fields
+            .into_iter()
+            .partition(|field| field.attrs().enabled("build"));
 
         let is_optional = |f: &&Field| {
             self.render_default_tokens(f).ok().flatten().is_some()
@@ -51,15 +123,21 @@ impl Render for StructSyntax {
             })
             .collect();
 
-        let const_param_idents: Vec<_> = required
-            .iter()
-            .map(|f| format_ident!("{}", f.name().to_string().to_uppercase()))
-            .collect();
+        // stay clear of the type's own parameters, e.g. a
+        // field `n` on `struct Buffer<const N: usize>`
+        let mut const_param_idents: Vec<syn::Ident> = vec![];
+
+        for field in &required {
+            let name = field.name().to_ident().unraw().to_string().to_uppercase();
+            const_param_idents.push(unique_param(&name, generics, &const_param_idents));
+        }
+
+        let value_param = unique_param("V", generics, &const_param_idents);
 
         let builder_fields: Vec<_> = fields
             .iter()
             .map(|field| {
-                let name = field.name();
+                let name = field.name().to_ident();
                 if self.render_option_inner(field).is_some()
                     && self.render_default_tokens(field).ok().flatten().is_none()
                 {
@@ -67,17 +145,20 @@ impl Render for StructSyntax {
                     quote!(#name: #ty)
                 } else {
                     let ty = field.ty();
-                    quote!(#name: ::std::option::Option<#ty>)
+                    quote!(#name: ::core::option::Option<#ty>)
                 }
             })
             .collect();
 
-        let field_names: Vec<_> = fields.iter().map(|f| f.name()).collect();
+        // the builder's own field, named clear of the fields it stores
+        let stored: Vec<_> = fields.iter().map(|f| f.name().to_ident()).collect();
+        let marker = unique_param("__marker", generics, &stored);
+
         let init_fields: Vec<_> = fields
             .iter()
             .map(|f| {
-                let name = f.name();
-                quote!(#name: ::std::option::Option::None)
+                let name = f.name().to_ident();
+                quote!(#name: ::core::option::Option::None)
             })
             .collect();
 
@@ -88,9 +169,11 @@ impl Render for StructSyntax {
 
         let const_param_refs: Vec<_> = const_param_idents.iter().map(|id| quote!(#id)).collect();
         let const_param_true: Vec<_> = const_param_idents.iter().map(|_| quote!(true)).collect();
+        let const_param_init: Vec<_> = const_param_idents.iter().map(|_| quote!(false)).collect();
         let builder_struct = quote! {
-            #vis struct #build_ident <#(#lifetime_params,)* #(#type_params,)* #(#const_param_defs,)*> #where_generics {
+            #vis struct #build_ident <#(#params,)* #(#const_param_defs,)*> #where_generics {
                 #(#builder_fields,)*
+                #marker: ::core::marker::PhantomData<fn() -> #ident #type_generics>,
             }
         };
 
@@ -98,7 +181,7 @@ impl Render for StructSyntax {
             .iter()
             .enumerate()
             .map(|(i, field)| -> syn::Result<TokenStream> {
-                let fname = field.name();
+                let fname = field.name().to_ident();
                 let ty = field.ty();
                 let method_name = match self.render_custom_method_name(field)? {
                     Some(id) => quote!(#id),
@@ -124,11 +207,12 @@ impl Render for StructSyntax {
                     .map(|(j, id)| if j == i { quote!(true) } else { quote!(#id) })
                     .collect();
 
-                let move_fields: Vec<_> = field_names
+                let move_fields: Vec<_> = fields
                     .iter()
-                    .map(|n| {
-                        if n.to_string() == fname.to_string() {
-                            quote!(#n: ::std::option::Option::Some(value.into()))
+                    .map(|f| {
+                        let n = f.name().to_ident();
+                        if n == fname {
+                            quote!(#n: ::core::option::Option::Some(value.into()))
                         } else {
                             quote!(#n: self.#n)
                         }
@@ -136,21 +220,22 @@ impl Render for StructSyntax {
                     .collect();
 
                 Ok(quote! {
-                    impl <#(#lifetime_params,)* #(#type_params,)* #(#other_const_params,)*> #build_ident <#(#lifetime_params,)* #(#type_param_idents,)* #(#impl_const_refs,)*> #where_generics {
-                        pub fn #method_name<V: Into<#ty>>(self, value: V) -> #build_ident <#(#lifetime_params,)* #(#type_param_idents,)* #(#ret_const_refs,)*> {
+                    impl <#(#impl_params,)* #(#other_const_params,)*> #build_ident <#(#param_refs,)* #(#impl_const_refs,)*> #where_generics {
+                        pub fn #method_name<#value_param: ::core::convert::Into<#ty>>(self, value: #value_param) -> #build_ident <#(#param_refs,)* #(#ret_const_refs,)*> {
                             #build_ident {
                                 #(#move_fields,)*
+                                #marker: ::core::marker::PhantomData,
                             }
                         }
                     }
                 })
             })
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
         let optional_setters: Vec<TokenStream> = optional
             .iter()
             .map(|field| -> syn::Result<TokenStream> {
-                let fname = field.name();
+                let fname = field.name().to_ident();
                 let ty = field.ty();
                 let method_name = match self.render_custom_method_name(field)? {
                     Some(id) => quote!(#id),
@@ -158,18 +243,18 @@ impl Render for StructSyntax {
                 };
 
                 Ok(quote! {
-                    pub fn #method_name<V: Into<#ty>>(mut self, value: V) -> Self {
-                        self.#fname = ::std::option::Option::Some(value.into());
+                    pub fn #method_name<#value_param: ::core::convert::Into<#ty>>(mut self, value: #value_param) -> Self {
+                        self.#fname = ::core::option::Option::Some(value.into());
                         self
                     }
                 })
             })
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
         let option_setters: Vec<TokenStream> = option_fields
             .iter()
             .map(|field| -> syn::Result<TokenStream> {
-                let fname = field.name();
+                let fname = field.name().to_ident();
                 let inner_ty = self.render_option_inner(field).unwrap();
                 let method_name = match self.render_custom_method_name(field)? {
                     Some(id) => quote!(#id),
@@ -177,13 +262,13 @@ impl Render for StructSyntax {
                 };
 
                 Ok(quote! {
-                    pub fn #method_name<V: Into<#inner_ty>>(mut self, value: V) -> Self {
-                        self.#fname = ::std::option::Option::Some(value.into());
+                    pub fn #method_name<#value_param: ::core::convert::Into<#inner_ty>>(mut self, value: #value_param) -> Self {
+                        self.#fname = ::core::option::Option::Some(value.into());
                         self
                     }
                 })
             })
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
         let all_const_generic_params: Vec<_> = const_param_idents
             .iter()
@@ -199,7 +284,7 @@ impl Render for StructSyntax {
             quote!()
         } else {
             quote! {
-                impl <#(#lifetime_params,)* #(#type_params,)* #(#all_const_generic_params,)*> #build_ident <#(#lifetime_params,)* #(#type_param_idents,)* #(#const_param_refs,)*> #where_generics {
+                impl <#(#impl_params,)* #(#all_const_generic_params,)*> #build_ident <#(#param_refs,)* #(#const_param_refs,)*> #where_generics {
                     #(#all_optional_setters)*
                 }
             }
@@ -209,25 +294,54 @@ impl Render for StructSyntax {
             .iter()
             .map(|field| -> syn::Result<TokenStream> {
                 let fname = field.name();
+                let store = fname.to_ident();
                 let default = self.render_default_tokens(field)?;
                 let is_option = self.render_option_inner(field).is_some() && default.is_none();
 
                 Ok(if is_option {
-                    quote!(#fname: self.#fname)
+                    quote!(#fname: self.#store)
                 } else if let Some(default) = default {
-                    quote!(#fname: self.#fname.unwrap_or_else(|| #default.into()))
+                    quote!(#fname: self.#store.unwrap_or_else(|| #default.into()))
                 } else {
-                    quote!(#fname: self.#fname.unwrap())
+                    quote!(#fname: self.#store.unwrap())
                 })
             })
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
+        // only fall back to `Default` when some fields are not part of the builder
+        let rest = if skipped.is_empty() {
+            quote!()
+        } else if is_struct {
+            quote!(..::core::default::Default::default())
+        } else {
+            let names = skipped.iter().map(|f| {
+                let name = f.name();
+                quote!(#name)
+            });
+            quote!(#(#names: ::core::default::Default::default(),)*)
+        };
+
+        // filling in skipped fields needs `Default`, which generic
+        // fields only have when their type parameters do
+        let attributes = Attrs::parse(&input.attrs)?;
+        let mut bounds = Bounds::new(generics, attributes.bound("build")?);
+
+        if is_struct && !skipped.is_empty() {
+            bounds.push(syn::parse_quote!(#ident #type_generics: ::core::default::Default));
+        } else {
+            for field in &skipped {
+                bounds.require(field.ty(), &quote!(::core::default::Default));
+            }
+        }
+
+        let build_generics = bounds.generics();
+        let build_where = &build_generics.where_clause;
         let build_impl = quote! {
-            impl <#(#lifetime_params,)* #(#type_params,)*> #build_ident <#(#lifetime_params,)* #(#type_param_idents,)* #(#const_param_true,)*> #where_generics {
+            impl <#(#impl_params,)*> #build_ident <#(#param_refs,)* #(#const_param_true,)*> #build_where {
                 pub fn build(self) -> #ident #type_generics {
-                    #ident {
+                    #target {
                         #(#build_fields_assign,)*
-                        ..::std::default::Default::default()
+                        #rest
                     }
                 }
             }
@@ -235,25 +349,41 @@ impl Render for StructSyntax {
 
         let new_impl = quote! {
             impl #impl_generics #ident #type_generics #where_generics {
-                pub fn new() -> #build_ident <#(#lifetime_params,)* #(#type_param_idents,)*> {
+                pub fn #ctor_ident() -> #build_ident <#(#param_refs,)* #(#const_param_init,)*> {
                     #build_ident {
                         #(#init_fields,)*
+                        #marker: ::core::marker::PhantomData,
                     }
                 }
             }
         };
 
+        let buildable_impl = if is_struct && attributes.setting("build", "buildable")?.is_some() {
+            let facade = attributes.facade()?;
+
+            quote! {
+                impl #impl_generics #facade::Buildable for #ident #type_generics #where_generics {
+                    type Builder = #build_ident <#(#param_refs,)* #(#const_param_init,)*>;
+
+                    fn builder() -> Self::Builder {
+                        Self::#ctor_ident()
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+
         Ok(quote! {
             #builder_struct
             #(#required_setters)*
             #optional_setters_block
             #build_impl
             #new_impl
+            #buildable_impl
         })
     }
-}
 
-impl StructSyntax {
     fn render_option_inner<'a>(&self, field: &'a Field) -> Option<&'a syn::Type> {
         let syn::Type::Path(type_path) = field.ty() else {
             return None;
@@ -278,21 +408,34 @@ impl StructSyntax {
 
     fn render_custom_method_name(&self, field: &Field) -> syn::Result<Option<proc_macro2::Ident>> {
         let build_args = field.attrs().get("build")?;
-        Ok(build_args
+        let name = build_args
             .iter()
             .find_map(|arg| arg.as_attr())
             .and_then(|attr| {
-                attr.args().iter().find_map(|a| {
-                    if !a.path().is_ident("__value") {
-                        return None;
-                    }
-
-                    a.as_lit().and_then(|lit| match lit {
-                        syn::Lit::Str(s) => Some(format_ident!("{}", s.value())),
+                attr.args()
+                    .iter()
+                    .filter(|a| a.path().is_ident("__value"))
+                    .find_map(|a| match a.as_lit() {
+                        Some(syn::Lit::Str(s)) => Some(s.clone()),
                         _ => None,
                     })
-                })
-            }))
+            });
+
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        syn::parse_str::<syn::Ident>(&name.value())
+            .map(|mut ident| {
+                ident.set_span(name.span());
+                Some(ident)
+            })
+            .map_err(|_| {
+                name.error(&format!(
+                    "`{}` is not a valid builder method name",
+                    name.value()
+                ))
+            })
     }
 
     fn render_default_tokens(
@@ -1,5 +1,5 @@
 use quote::quote;
-use syn::punctuated::Punctuated;
+use syn::{punctuated::Punctuated, spanned::Spanned};
 
 use crate::{Error, core::Attr};
 
@@ -98,7 +98,7 @@ impl Arg {
     }
 
     #[allow(unused)]
-    pub fn to_lit(self) -> Option<syn::Lit> {
+    pub fn into_lit(self) -> Option<syn::Lit> {
         match self {
             Self::Literal(_, v) => Some(v),
             _ => None,
@@ -106,7 +106,7 @@ impl Arg {
     }
 
     #[allow(unused)]
-    pub fn to_ident(self) -> Option<syn::Ident> {
+    pub fn into_ident(self) -> Option<syn::Ident> {
         match self {
             Self::Ident(_, v) => Some(v),
             _ => None,
@@ -114,7 +114,7 @@ impl Arg {
     }
 
     #[allow(unused)]
-    pub fn to_attr(self) -> Option<Attr> {
+    pub fn into_attr(self) -> Option<Attr> {
         match self {
             Self::Attr(attr) => Some(attr),
             _ => None,
@@ -162,7 +162,7 @@ impl syn::parse::Parse for Arg {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.peek(syn::LitStr) {
             let lit: syn::Lit = input.parse()?;
-            let path: syn::Path = syn::parse_quote!(__value);
+            let path: syn::Path = syn::parse_quote_spanned!(lit.span()=> __value);
             return Ok(Arg::from_lit(path, lit));
         }
 
@@ -198,7 +198,7 @@ impl syn::parse::Parse for Arg {
         }
 
         let expr: syn::Expr = input.parse()?;
-        let path: syn::Path = syn::parse_quote!(__expr);
+        let path: syn::Path = syn::parse_quote_spanned!(expr.span()=> __expr);
         Ok(Arg::from_expr(path, expr))
     }
 }
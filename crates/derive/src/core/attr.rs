@@ -1,19 +1,26 @@
 use quote::quote;
 use syn::punctuated::Punctuated;
 
-use crate::core::Arg;
+use crate::{Combine, Error, core::Arg};
+
+/// field arguments that replace one another, so a field's
+/// own `get(copy)` overrides a struct wide `get(clone)`
+const EXCLUSIVE: &[&[&str]] = &[&["copy", "clone"]];
 
 #[repr(transparent)]
 #[derive(Clone)]
 pub struct Attrs(Vec<Attr>);
 
 impl Attrs {
+    /// parse every `#[moxy(...)]` attribute, ignoring
+    /// attributes that belong to other crates
     pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
         Ok(Self(
             attrs
                 .iter()
-                .filter_map(|attr| Attr::parse(attr).ok())
-                .collect(),
+                .filter(|attr| attr.path().is_ident("moxy"))
+                .map(Attr::parse)
+                .combine()?,
         ))
     }
 
@@ -44,8 +51,9 @@ impl Attrs {
         let raw: Vec<Arg> = self
             .0
             .iter()
-            .filter(|a| a.path().is_ident("moxy") && a.exists(name))
-            .flat_map(|a| a.get(name).cloned())
+            .filter(|a| a.path().is_ident("moxy"))
+            .flat_map(|a| a.args().iter().filter(|arg| arg.path().is_ident(name)))
+            .cloned()
             .collect();
 
         let mut result: Vec<Arg> = vec![];
@@ -76,6 +84,173 @@ impl Attrs {
 
         Ok(result)
     }
+
+    /// every `alias = "..."` string given to the named attribute,
+    /// e.g. `#[moxy(str(alias = "name"))]`, across all of its
+    /// attributes rather than merged into a single value
+    pub fn aliases(&self, name: &str) -> syn::Result<Vec<String>> {
+        Ok(self
+            .0
+            .iter()
+            .flat_map(|attr| attr.args())
+            .filter(|arg| arg.path().is_ident(name))
+            .filter_map(|arg| arg.as_attr())
+            .flat_map(|attr| attr.args().iter())
+            .filter(|arg| arg.path().is_ident("alias"))
+            .filter_map(|arg| match arg.as_lit() {
+                Some(syn::Lit::Str(s)) => Some(s.value()),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// the `setting` given to the named attribute, e.g.
+    /// `styled` in `#[moxy(display(styled))]`
+    pub fn setting(&self, name: &str, setting: &str) -> syn::Result<Option<Arg>> {
+        Ok(self
+            .get(name)?
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .find_map(|attr| attr.get(setting).cloned()))
+    }
+
+    /// the where clause predicates given to the named attribute
+    /// with `bound = "..."`, e.g. `#[moxy(display(bound = "T: Display"))]`
+    pub fn bound(&self, name: &str) -> syn::Result<Option<Vec<syn::WherePredicate>>> {
+        let Some(arg) = self.setting(name, "bound")? else {
+            return Ok(None);
+        };
+
+        let Some(syn::Lit::Str(s)) = arg.as_lit() else {
+            return Err(arg
+                .path()
+                .error("expected a string of predicates, e.g. `bound = \"T: Display\"`"));
+        };
+
+        let predicates =
+            s.parse_with(Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated)?;
+
+        Ok(Some(predicates.into_iter().collect()))
+    }
+
+    /// the path to the `moxy` facade that provides the runtime
+    /// traits, `::moxy` unless `#[moxy(crate = ...)]` is set.
+    /// the traits are only implemented when asked for, since
+    /// `moxy-derive` can be used without the facade
+    pub fn facade(&self) -> syn::Result<syn::Path> {
+        Ok(self
+            .crate_path()?
+            .unwrap_or_else(|| syn::parse_quote!(::moxy)))
+    }
+
+    /// the path given to `#[moxy(crate = ...)]`, used to reach the
+    /// runtime dependencies re-exported by the `moxy` facade
+    pub fn crate_path(&self) -> syn::Result<Option<syn::Path>> {
+        let Some(arg) = self.0.iter().find_map(|a| a.get("crate")) else {
+            return Ok(None);
+        };
+
+        match arg {
+            Arg::Ident(_, ident) => Ok(Some(ident.clone().into())),
+            Arg::Expr(_, syn::Expr::Path(p)) if p.qself.is_none() => Ok(Some(p.path.clone())),
+            Arg::Literal(_, syn::Lit::Str(s)) => s.parse().map(Some),
+            other => Err(other
+                .path()
+                .error("expected a path, e.g. `crate = my_framework::moxy`")),
+        }
+    }
+
+    /// whether the named attribute is set and not opted
+    /// out of with `skip`, e.g. `#[moxy(get(skip))]`
+    pub fn enabled(&self, name: &str) -> bool {
+        self.exists(name)
+            && !self
+                .names(name)
+                .is_ok_and(|names| names.iter().any(|n| n == "skip"))
+    }
+
+    /// the arguments a struct level `#[moxy(name)]` gives each of its
+    /// fields, leaving out the `settings` that configure the struct
+    /// itself, or `None` when the struct doesn't apply `name` to its fields
+    pub fn defaults(&self, name: &str, settings: &[&str]) -> syn::Result<Option<Vec<Arg>>> {
+        let mut enabled = false;
+        let mut defaults = vec![];
+
+        for arg in self.get(name)? {
+            match arg {
+                Arg::Flag(_) => enabled = true,
+                Arg::Attr(attr) => {
+                    for arg in attr.args() {
+                        if !settings.iter().any(|s| arg.path().is_ident(s)) {
+                            enabled = true;
+                            defaults.push(arg.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(enabled.then_some(defaults))
+    }
+
+    /// apply a struct's `defaults` for the named attribute to this field,
+    /// except the arguments the field sets itself and when it has `skip`
+    pub fn inherit(&mut self, name: &str, defaults: &[Arg]) -> syn::Result<()> {
+        let own = self.names(name)?;
+
+        if own.iter().any(|n| n == "skip") {
+            return Ok(());
+        }
+
+        let args: Punctuated<Arg, syn::Token![,]> = defaults
+            .iter()
+            .filter(|arg| {
+                let group = EXCLUSIVE
+                    .iter()
+                    .find(|group| group.iter().any(|g| arg.path().is_ident(g)))
+                    .copied()
+                    .unwrap_or_default();
+
+                !own.iter()
+                    .any(|n| arg.path().is_ident(n) || group.contains(&n.as_str()))
+            })
+            .cloned()
+            .collect();
+
+        if args.is_empty() && self.exists(name) {
+            return Ok(());
+        }
+
+        let path = syn::Path::from(syn::Ident::new(name, proc_macro2::Span::call_site()));
+        let arg = match args.is_empty() {
+            true => Arg::from_flag(path),
+            false => Arg::from_attr(Attr::new(path, args)),
+        };
+
+        self.0.push(Attr::new(
+            syn::parse_quote!(moxy),
+            std::iter::once(arg).collect(),
+        ));
+
+        Ok(())
+    }
+
+    /// the names of the arguments given to the named attribute
+    fn names(&self, name: &str) -> syn::Result<Vec<String>> {
+        Ok(self
+            .get(name)?
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .flat_map(|attr| attr.args())
+            .filter_map(|arg| arg.path().get_ident().map(|i| i.to_string()))
+            .collect())
+    }
+
+    /// the first `alias = "..."` string given to the named attribute
+    pub fn alias(&self, name: &str) -> syn::Result<Option<String>> {
+        Ok(self.aliases(name)?.into_iter().next())
+    }
 }
 
 impl quote::ToTokens for Attrs {
@@ -134,6 +309,27 @@ impl Attr {
         self.args.iter().any(|arg| arg.path().is_ident(ident))
     }
 
+    /// split the args into options and the positional format
+    /// arguments (bare identifiers and expressions) that follow
+    /// a string literal, as in `display("{} {}", a, self.b, pretty)`
+    pub fn format_args(&self) -> (Vec<&Arg>, Vec<&Arg>) {
+        let start = self
+            .args
+            .iter()
+            .position(|arg| arg.path().is_ident("__value"))
+            .map_or(self.args.len(), |i| i + 1);
+
+        let (options, exprs): (Vec<_>, Vec<_>) =
+            self.args.iter().enumerate().partition(|(i, arg)| {
+                *i < start || !(arg.is_flag() || arg.path().is_ident("__expr"))
+            });
+
+        (
+            options.into_iter().map(|(_, arg)| arg).collect(),
+            exprs.into_iter().map(|(_, arg)| arg).collect(),
+        )
+    }
+
     #[allow(unused)]
     pub fn get(&self, ident: &str) -> Option<&Arg> {
         self.args.iter().find(|arg| arg.path().is_ident(ident))
@@ -156,15 +352,18 @@ impl Attr {
                     // identical — deduplicate silently
                 }
                 Some(_) => {
+                    let name = arg
+                        .path()
+                        .get_ident()
+                        .map(|i| i.to_string())
+                        .unwrap_or_default();
+
                     return Err(syn::Error::new_spanned(
                         arg.path(),
-                        format!(
-                            "conflicting values for `{}`",
-                            arg.path()
-                                .get_ident()
-                                .map(|i| i.to_string())
-                                .unwrap_or_default()
-                        ),
+                        match name.as_str() {
+                            "__value" => "conflicting string values".to_string(),
+                            _ => format!("conflicting values for `{name}`"),
+                        },
                     ));
                 }
                 None => {
@@ -0,0 +1,79 @@
+use proc_macro2::{TokenStream, TokenTree};
+
+///
+/// ## Bounds
+/// the where clause predicates a generated impl needs,
+/// inferred from the fields it uses or given by the
+/// user through `#[moxy(name(bound = "..."))]`
+///
+#[derive(Clone)]
+pub struct Bounds<'a> {
+    generics: &'a syn::Generics,
+    custom: Option<Vec<syn::WherePredicate>>,
+    predicates: Vec<syn::WherePredicate>,
+}
+
+impl<'a> Bounds<'a> {
+    pub fn new(generics: &'a syn::Generics, custom: Option<Vec<syn::WherePredicate>>) -> Self {
+        Self {
+            generics,
+            custom,
+            predicates: vec![],
+        }
+    }
+
+    /// require `bound` of every type parameter that appears in `ty`
+    pub fn require(&mut self, ty: &syn::Type, bound: &TokenStream) -> &mut Self {
+        let tokens = quote::quote!(#ty);
+
+        for param in self.generics.type_params() {
+            let ident = &param.ident;
+
+            if !mentions(tokens.clone(), ident) {
+                continue;
+            }
+
+            let predicate: syn::WherePredicate = syn::parse_quote!(#ident: #bound);
+
+            if !self.predicates.contains(&predicate) {
+                self.predicates.push(predicate);
+            }
+        }
+
+        self
+    }
+
+    /// add a predicate as written, e.g. `Self: Default`
+    pub fn push(&mut self, predicate: syn::WherePredicate) -> &mut Self {
+        if !self.predicates.contains(&predicate) {
+            self.predicates.push(predicate);
+        }
+
+        self
+    }
+
+    /// the generics of the generated impl, with the user's
+    /// predicates taking the place of the inferred ones
+    pub fn generics(&self) -> syn::Generics {
+        let mut generics = self.generics.clone();
+        let predicates = self.custom.as_ref().unwrap_or(&self.predicates);
+
+        if !predicates.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(predicates.iter().cloned());
+        }
+
+        generics
+    }
+}
+
+/// whether `ident` appears anywhere in `tokens`
+fn mentions(tokens: TokenStream, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(id) => &id == ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
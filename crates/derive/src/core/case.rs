@@ -0,0 +1,118 @@
+/// convert a `PascalCase` identifier such as `HttpServer`
+/// or `HTTPServer` into `snake_case` (`http_server`)
+pub fn to_snake_case(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut out = String::with_capacity(value.len() + 4);
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+
+            if prev != '_' && (prev.is_lowercase() || prev.is_numeric() || next_is_lower) {
+                out.push('_');
+            }
+        }
+
+        out.extend(c.to_lowercase());
+    }
+
+    out
+}
+
+///
+/// ## Case
+/// a naming convention that names can be converted
+/// to with `#[moxy(display(rename_all = "..."))]`
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Upper,
+    Camel,
+    Snake,
+    Kebab,
+    ScreamingSnake,
+    ScreamingKebab,
+    Pascal,
+}
+
+impl Case {
+    // the same names serde's `rename_all` accepts
+    const NAMES: [(&'static str, Self); 8] = [
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("camelCase", Self::Camel),
+        ("snake_case", Self::Snake),
+        ("kebab-case", Self::Kebab),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+        ("PascalCase", Self::Pascal),
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, case)| *case)
+    }
+
+    pub fn parse(lit: &syn::LitStr) -> syn::Result<Self> {
+        let value = lit.value();
+
+        Self::from_name(&value).ok_or_else(|| {
+            let names: Vec<_> = Self::NAMES
+                .iter()
+                .map(|(name, _)| format!("`{name}`"))
+                .collect();
+
+            syn::Error::new(
+                lit.span(),
+                format!(
+                    "unknown case `{value}`; expected one of {}",
+                    names.join(", ")
+                ),
+            )
+        })
+    }
+
+    /// convert a `snake_case` or `PascalCase` name to this case
+    pub fn apply(&self, value: &str) -> String {
+        let snake = to_snake_case(value);
+        let words = snake.split('_').filter(|word| !word.is_empty());
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        };
+
+        match self {
+            Self::Lower => value.to_lowercase(),
+            Self::Upper => value.to_uppercase(),
+            Self::Snake => words.collect::<Vec<_>>().join("_"),
+            Self::Kebab => words.collect::<Vec<_>>().join("-"),
+            Self::ScreamingSnake => words
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            Self::ScreamingKebab => words
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("-"),
+            Self::Pascal => words.map(capitalize).collect::<Vec<String>>().concat(),
+            Self::Camel => words
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_string()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .concat(),
+        }
+    }
+}
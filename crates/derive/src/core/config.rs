@@ -0,0 +1,253 @@
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, time::SystemTime};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::core::{Case, unknown};
+
+const FILE: &str = "moxy.toml";
+
+/// every table a `moxy.toml` may contain and the keys allowed in it
+const TABLES: &[(&str, &[&str])] = &[
+    ("display", &["style", "pretty", "color", "rename_all"]),
+    ("get", &["vis"]),
+    ("set", &["prefix"]),
+];
+
+const STYLES: &[&str] = &["debug", "compact", "keyvalue", "map", "json"];
+
+/// a `moxy.toml` as last read, or the error parsing it, along with
+/// when it was modified so an edited file is read again
+type Entry = (Option<SystemTime>, Result<Option<toml::Table>, String>);
+
+thread_local! {
+    /// the `moxy.toml` of each crate read so far, so that every derive
+    /// in a crate doesn't read and parse it again. only the toml is kept,
+    /// since spans can't outlive the macro call that created them
+    static CACHE: RefCell<HashMap<PathBuf, Entry>> = RefCell::default();
+}
+
+///
+/// ## Config
+/// crate wide defaults read from a `moxy.toml` next to the
+/// deriving crate's `Cargo.toml`, each of them overridden
+/// by the matching `#[moxy(...)]` attribute
+///
+#[derive(Clone, Default)]
+pub struct Config {
+    pub path: Option<PathBuf>,
+    pub display: DisplayConfig,
+    pub get: GetConfig,
+    pub set: SetConfig,
+}
+
+/// the `[display]` table
+#[derive(Clone, Default)]
+pub struct DisplayConfig {
+    pub style: Option<String>,
+    pub pretty: bool,
+    pub theme: Option<String>,
+    pub rename_all: Option<Case>,
+}
+
+/// the `[get]` table
+#[derive(Clone, Default)]
+pub struct GetConfig {
+    pub vis: Option<syn::Visibility>,
+}
+
+/// the `[set]` table
+#[derive(Clone, Default)]
+pub struct SetConfig {
+    pub prefix: Option<String>,
+}
+
+impl Config {
+    /// read the `moxy.toml` of the crate being compiled,
+    /// or the default config when it has none
+    pub fn load() -> syn::Result<Self> {
+        let Some(dir) = std::env::var_os("CARGO_MANIFEST_DIR") else {
+            return Ok(Self::default());
+        };
+
+        let path = PathBuf::from(dir).join(FILE);
+        let Some(table) = read(&path).map_err(|message| error(&message))? else {
+            return Ok(Self::default());
+        };
+
+        let mut config = Self::parse(&table)?;
+        config.path = Some(path);
+        Ok(config)
+    }
+
+    fn parse(root: &toml::Table) -> syn::Result<Self> {
+        let mut config = Self::default();
+        let names: Vec<_> = TABLES.iter().map(|(name, _)| *name).collect();
+
+        for (name, value) in root {
+            let Some((_, keys)) = TABLES.iter().find(|(table, _)| table == name) else {
+                return Err(error(&unknown(
+                    &format!("unknown table `[{name}]`"),
+                    name,
+                    &names,
+                )));
+            };
+
+            let Some(table) = value.as_table() else {
+                return Err(error(&format!("`{name}` must be a table")));
+            };
+
+            for (key, value) in table {
+                if !keys.contains(&key.as_str()) {
+                    return Err(error(&unknown(
+                        &format!("unknown key `{key}` in `[{name}]`"),
+                        key,
+                        keys,
+                    )));
+                }
+
+                let key = format!("{name}.{key}");
+
+                match key.as_str() {
+                    "display.style" => {
+                        let style = string(&key, value)?;
+
+                        if !STYLES.contains(&style) {
+                            return Err(error(&unknown(
+                                &format!("unknown display style `{style}`"),
+                                style,
+                                STYLES,
+                            )));
+                        }
+
+                        if style == "json" && !cfg!(feature = "json") {
+                            return Err(error(
+                                "`display.style = \"json\"` requires the `json` feature of moxy",
+                            ));
+                        }
+
+                        config.display.style = Some(style.to_string());
+                    }
+                    "display.pretty" => {
+                        config.display.pretty = value
+                            .as_bool()
+                            .ok_or_else(|| error("`display.pretty` must be a boolean"))?;
+                    }
+                    "display.color" => {
+                        let theme = match value {
+                            toml::Value::Boolean(enabled) => enabled.then(String::new),
+                            toml::Value::String(theme) => Some(theme.clone()),
+                            _ => {
+                                return Err(error(
+                                    "`display.color` must be a boolean or a theme name",
+                                ));
+                            }
+                        };
+
+                        if theme.is_some() && !cfg!(feature = "color") {
+                            return Err(error(
+                                "`display.color` requires the `color` feature of moxy",
+                            ));
+                        }
+
+                        config.display.theme = theme;
+                    }
+                    "display.rename_all" => {
+                        let case = string(&key, value)?;
+                        let lit = syn::LitStr::new(case, Span::call_site());
+                        config.display.rename_all = Some(
+                            Case::parse(&lit)
+                                .map_err(|err| error(&format!("`display.rename_all`: {err}")))?,
+                        );
+                    }
+                    "get.vis" => {
+                        let vis = string(&key, value)?;
+                        config.get.vis = Some(syn::parse_str(vis).map_err(|_| {
+                            error(&format!(
+                                "`get.vis` must be a visibility, e.g. \"pub(crate)\", found \"{vis}\""
+                            ))
+                        })?);
+                    }
+                    "set.prefix" => {
+                        let prefix = string(&key, value)?;
+
+                        if prefix.is_empty() {
+                            return Err(error(
+                                "`set.prefix` must not be empty, since setters on tuple fields would be named `0`, `1` and so on",
+                            ));
+                        }
+
+                        if syn::parse_str::<syn::Ident>(&format!("{prefix}x")).is_err() {
+                            return Err(error(&format!(
+                                "`set.prefix` must start an identifier, found \"{prefix}\""
+                            )));
+                        }
+
+                        config.set.prefix = Some(prefix.to_string());
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// a reference to the file that has cargo rebuild the deriving
+    /// crate whenever the file changes. a crate without a `moxy.toml`
+    /// has nothing to reference, so cargo can't tell when one is added
+    pub fn track(&self) -> TokenStream {
+        if self.path.is_none() {
+            return quote!();
+        }
+
+        let file = format!("/{FILE}");
+        quote!(
+            const _: &[u8] = ::core::include_bytes!(::core::concat!(
+                ::core::env!("CARGO_MANIFEST_DIR"),
+                #file
+            ));
+        )
+    }
+}
+
+/// the table in the `moxy.toml` at `path`, read
+/// again only when the file was modified since
+fn read(path: &PathBuf) -> Result<Option<toml::Table>, String> {
+    let modified = std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok();
+
+    CACHE.with_borrow_mut(|cache| {
+        if let Some((time, table)) = cache.get(path)
+            && *time == modified
+            && modified.is_some()
+        {
+            return table.clone();
+        }
+
+        let table = std::fs::read_to_string(path).ok().map(|source| {
+            source.parse::<toml::Table>().map_err(|err| {
+                let line = err
+                    .span()
+                    .map_or(1, |span| source[..span.start].matches('\n').count() + 1);
+
+                format!("line {line}: {}", err.message().trim_end())
+            })
+        });
+
+        let table = table.transpose();
+        cache.insert(path.clone(), (modified, table.clone()));
+        table
+    })
+}
+
+fn string<'a>(key: &str, value: &'a toml::Value) -> syn::Result<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| error(&format!("`{key}` must be a string")))
+}
+
+fn error(message: &str) -> syn::Error {
+    syn::Error::new(Span::call_site(), format!("{FILE}: {message}"))
+}
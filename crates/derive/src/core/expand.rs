@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{Error, core::Attrs};
+
+///
+/// ## Expand
+/// write what a derive generated for a type to
+/// `<dir>/<Type>.<derive>.rs`, for every type when
+/// `MOXY_EXPAND_DIR` is set and otherwise only for
+/// types with `#[moxy(debug_expand)]`. see [`expand_dir`]
+/// for where the files go
+///
+pub fn expand(
+    derive: &str,
+    tokens: proc_macro::TokenStream,
+    output: TokenStream,
+) -> syn::Result<TokenStream> {
+    // reading the variable through `option_env!` has cargo rebuild
+    // the crate whenever it changes, so setting, moving or unsetting
+    // it applies to crates that were already built
+    let track = quote! {
+        const _: ::core::option::Option<&str> = ::core::option_env!("MOXY_EXPAND_DIR");
+    };
+
+    let input: syn::DeriveInput = syn::parse(tokens)?;
+    let attrs = Attrs::parse(&input.attrs)?;
+
+    if std::env::var_os("MOXY_EXPAND_DIR").is_none() && !attrs.exists("debug_expand") {
+        return Ok(quote!(#output #track));
+    }
+
+    let dir = expand_dir();
+    let path = dir.join(format!("{}.{derive}.rs", input.ident));
+    let source = format!("// #[moxy] {derive} for `{}`\n{output}\n", input.ident);
+
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&path, source))
+        .map_err(|err| {
+            input.ident.error(&format!(
+                "failed to write the expansion to `{}`: {err}",
+                path.display()
+            ))
+        })?;
+
+    Ok(quote!(#output #track))
+}
+
+/// where expansions are written: `MOXY_EXPAND_DIR`, taken from the
+/// crate's `Cargo.toml` when relative, else `moxy-expand` under
+/// `CARGO_TARGET_DIR`, else under `target` next to the workspace's
+/// `Cargo.lock`, which is where a plain `cargo build` puts its output
+fn expand_dir() -> PathBuf {
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+
+    if let Some(dir) = std::env::var_os("MOXY_EXPAND_DIR") {
+        return manifest_dir.join(dir);
+    }
+
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            manifest_dir
+                .ancestors()
+                .find(|dir| dir.join("Cargo.lock").is_file())
+                .unwrap_or(&manifest_dir)
+                .join("target")
+        });
+
+    target_dir.join("moxy-expand")
+}
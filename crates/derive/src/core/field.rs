@@ -1,11 +1,13 @@
 use quote::quote;
+use syn::ext::IdentExt;
 
-use crate::core::Attrs;
+use crate::core::{Arg, Attrs, Case, SerdeAttrs};
 
 #[derive(Clone)]
 pub struct Field {
     raw_attrs: Vec<syn::Attribute>,
     attrs: Attrs,
+    serde: SerdeAttrs,
     vis: syn::Visibility,
     name: FieldName,
     ty: syn::Type,
@@ -16,6 +18,7 @@ impl Field {
         Ok(Self {
             raw_attrs: field.attrs.clone(),
             attrs: Attrs::parse(&field.attrs)?,
+            serde: SerdeAttrs::parse(&field.attrs),
             vis: field.vis.clone(),
             name: match &field.ident {
                 None => syn::Index::from(i).into(),
@@ -25,6 +28,16 @@ impl Field {
         })
     }
 
+    /// apply the defaults a struct level `#[moxy(name)]` gives
+    /// its fields, when the struct has one
+    pub fn inherit(mut self, name: &str, defaults: Option<&[Arg]>) -> syn::Result<Self> {
+        if let Some(defaults) = defaults {
+            self.attrs.inherit(name, defaults)?;
+        }
+
+        Ok(self)
+    }
+
     pub fn raw_attrs(&self) -> &[syn::Attribute] {
         &self.raw_attrs
     }
@@ -33,6 +46,10 @@ impl Field {
         &self.attrs
     }
 
+    pub fn serde(&self) -> &SerdeAttrs {
+        &self.serde
+    }
+
     pub fn vis(&self) -> &syn::Visibility {
         &self.vis
     }
@@ -45,24 +62,13 @@ impl Field {
         &self.ty
     }
 
-    pub fn display_name(&self) -> syn::Result<String> {
-        let display = self.attrs.get("display")?;
-        Ok(display
-            .iter()
-            .find_map(|a| a.as_attr())
-            .and_then(|attr| {
-                attr.args().iter().find_map(|arg| {
-                    if arg.path().is_ident("alias") {
-                        arg.as_lit().and_then(|lit| match lit {
-                            syn::Lit::Str(s) => Some(s.value()),
-                            _ => None,
-                        })
-                    } else {
-                        None
-                    }
-                })
-            })
-            .unwrap_or_else(|| self.name.to_string()))
+    /// the name shown for this field, its `alias` when
+    /// set and otherwise its own name converted to `case`
+    pub fn display_name(&self, case: Option<Case>) -> syn::Result<String> {
+        Ok(self.attrs.alias("display")?.unwrap_or_else(|| {
+            let name = self.name.unraw();
+            case.map(|case| case.apply(&name)).unwrap_or(name)
+        }))
     }
 }
 
@@ -86,6 +92,22 @@ pub enum FieldName {
 }
 
 impl FieldName {
+    /// an identifier for this field, `_0`, `_1` and so on for tuple fields
+    pub fn to_ident(&self) -> syn::Ident {
+        match self {
+            Self::Ident(id) => id.clone(),
+            Self::Index(i) => quote::format_ident!("_{}", i.index),
+        }
+    }
+
+    /// the name as text, without the `r#` of a raw identifier
+    pub fn unraw(&self) -> String {
+        match self {
+            Self::Ident(id) => id.unraw().to_string(),
+            Self::Index(i) => i.index.to_string(),
+        }
+    }
+
     #[allow(unused)]
     pub fn is_index(&self) -> bool {
         matches!(self, Self::Index(_))
@@ -0,0 +1,23 @@
+use proc_macro2::Span;
+use quote::format_ident;
+
+/// a local variable for generated code, resolved at the macro's
+/// definition site so user fields and expressions can't shadow it
+pub fn local(name: &str) -> syn::Ident {
+    syn::Ident::new(name, Span::mixed_site())
+}
+
+/// a generic parameter named `name`, suffixed with `_` until it
+/// no longer clashes with the type's own parameters or `taken`
+pub fn unique_param(name: &str, generics: &syn::Generics, taken: &[syn::Ident]) -> syn::Ident {
+    let mut ident = format_ident!("{}", name);
+
+    while generics.type_params().any(|tp| tp.ident == ident)
+        || generics.const_params().any(|cp| cp.ident == ident)
+        || taken.contains(&ident)
+    {
+        ident = format_ident!("{}_", ident);
+    }
+
+    ident
+}
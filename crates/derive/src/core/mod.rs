@@ -1,7 +1,21 @@
 mod arg;
 mod attr;
+mod bounds;
+mod case;
+mod config;
+mod expand;
 mod field;
+mod ident;
+mod schema;
+mod serde;
 
 pub use arg::*;
 pub use attr::*;
+pub use bounds::*;
+pub use case::*;
+pub use config::*;
+pub use expand::*;
 pub use field::*;
+pub use ident::*;
+pub use schema::*;
+pub use serde::*;
@@ -0,0 +1,350 @@
+use crate::{
+    Combine, Error,
+    core::{Arg, Attrs},
+    params::Params,
+};
+
+/// every top level key understood inside `#[moxy(...)]`,
+/// regardless of which derive consumes it
+pub const KEYS: &[&str] = &[
+    "build",
+    "crate",
+    "debug_expand",
+    "default",
+    "deref",
+    "display",
+    "get",
+    "set",
+    "str",
+    "variant",
+];
+
+///
+/// ## Position
+/// where an attribute was written
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Container,
+    Variant,
+    Field,
+}
+
+impl Position {
+    const ALL: [Self; 3] = [Self::Container, Self::Variant, Self::Field];
+
+    fn describe(&self) -> &'static str {
+        match self {
+            Self::Container => "structs and enums",
+            Self::Variant => "enum variants",
+            Self::Field => "fields",
+        }
+    }
+}
+
+///
+/// ## Schema
+/// the arguments a derive accepts for its `#[moxy(key(...))]`
+/// attribute at each position, `None` meaning the key
+/// itself is not allowed there
+///
+/// `__flag` allows the bare key, e.g. `#[moxy(get)]`, `__assign`
+/// allows a value for it, e.g. `#[moxy(default = 1)]`, `__value`
+/// allows a bare string literal and `__expr` allows the
+/// positional format arguments that follow it
+///
+/// other entries name an argument along with the form it takes,
+/// `skip` for a flag, `alias =` for a value and `redact(..)` for
+/// a list, repeating the name for each form it accepts
+///
+pub struct Schema {
+    pub key: &'static str,
+    pub container: Option<&'static [&'static str]>,
+    pub variant: Option<&'static [&'static str]>,
+    pub field: Option<&'static [&'static str]>,
+}
+
+///
+/// ## Form
+/// how an argument is written
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Form {
+    Flag,
+    Value,
+    List,
+}
+
+impl Form {
+    fn of(arg: &Arg) -> Self {
+        match arg {
+            Arg::Flag(_) => Self::Flag,
+            Arg::Attr(_) => Self::List,
+            _ => Self::Value,
+        }
+    }
+
+    /// split a schema entry into its name and form
+    fn parse(entry: &str) -> (&str, Self) {
+        if let Some(name) = entry.strip_suffix(" =") {
+            (name, Self::Value)
+        } else if let Some(name) = entry.strip_suffix("(..)") {
+            (name, Self::List)
+        } else {
+            (entry, Self::Flag)
+        }
+    }
+
+    fn describe(&self, key: &str, name: &str) -> String {
+        match self {
+            Self::Flag => format!("`{key}({name})`"),
+            Self::Value => format!("`{key}({name} = ...)`"),
+            Self::List => format!("`{key}({name}(...))`"),
+        }
+    }
+}
+
+/// the names of the schema entries, without their forms
+fn names<'a>(allowed: &[&'a str]) -> Vec<&'a str> {
+    let mut names: Vec<_> = allowed.iter().map(|entry| Form::parse(entry).0).collect();
+    names.dedup();
+    names
+}
+
+impl Schema {
+    fn at(&self, position: Position) -> Option<&'static [&'static str]> {
+        match position {
+            Position::Container => self.container,
+            Position::Variant => self.variant,
+            Position::Field => self.field,
+        }
+    }
+
+    /// check every moxy attribute on the input, its variants
+    /// and its fields against this schema, reporting all errors
+    pub fn validate(&self, params: &Params) -> syn::Result<()> {
+        let mut targets = vec![(&params.input().attrs, Position::Container)];
+
+        match params {
+            Params::Struct(v) => {
+                for field in &v.data.fields {
+                    targets.push((&field.attrs, Position::Field));
+                }
+            }
+            Params::Enum(v) => {
+                for variant in &v.data.variants {
+                    targets.push((&variant.attrs, Position::Variant));
+
+                    for field in &variant.fields {
+                        targets.push((&field.attrs, Position::Field));
+                    }
+                }
+            }
+        }
+
+        targets
+            .into_iter()
+            .map(|(attrs, position)| self.validate_attrs(attrs, position))
+            .combine()?;
+
+        Ok(())
+    }
+
+    fn validate_attrs(&self, attrs: &[syn::Attribute], position: Position) -> syn::Result<()> {
+        Attrs::parse(attrs)?
+            .iter()
+            .flat_map(|attr| attr.args())
+            .map(|arg| {
+                let Some(key) = arg.path().get_ident().map(|i| i.to_string()) else {
+                    return Err(arg.path().error("expected a moxy attribute name"));
+                };
+
+                if !KEYS.contains(&key.as_str()) {
+                    return Err(arg.path().error(&unknown(
+                        &format!("unknown moxy attribute `{key}`"),
+                        &key,
+                        KEYS,
+                    )));
+                }
+
+                if matches!(key.as_str(), "crate" | "debug_expand")
+                    && position != Position::Container
+                {
+                    return Err(arg
+                        .path()
+                        .error(&format!("`{key}` can only be used on structs and enums")));
+                }
+
+                if key == "crate" && Form::of(arg) != Form::Value {
+                    return Err(arg
+                        .path()
+                        .error("expected a path, e.g. `crate = my_framework::moxy`"));
+                }
+
+                if key == "debug_expand" && !arg.is_flag() {
+                    return Err(arg.path().error("expected `debug_expand`"));
+                }
+
+                if key == self.key {
+                    self.validate_arg(arg, position)?;
+                }
+
+                Ok(())
+            })
+            .combine()?;
+
+        Ok(())
+    }
+
+    fn validate_arg(&self, arg: &Arg, position: Position) -> syn::Result<()> {
+        let key = self.key;
+        let Some(allowed) = self.at(position) else {
+            return Err(arg.path().error(&format!(
+                "`{key}` can only be used on {}",
+                self.positions(|_| true)
+            )));
+        };
+
+        if arg.is_flag() && !allowed.contains(&"__flag") {
+            let elsewhere = self.positions(|p| p.contains(&"__flag"));
+
+            return Err(arg.path().error(&if elsewhere.is_empty() {
+                format!("expected `{key}(...)`")
+            } else {
+                format!("`{key}` can only be used on {elsewhere}")
+            }));
+        }
+
+        if Form::of(arg) == Form::Value && !allowed.contains(&"__assign") {
+            let elsewhere = self.positions(|p| p.contains(&"__assign"));
+
+            return Err(arg.path().error(&if elsewhere.is_empty() {
+                format!("`{key}` does not take a value")
+            } else {
+                format!("`{key} = ...` can only be used on {elsewhere}")
+            }));
+        }
+
+        let Some(attr) = arg.as_attr() else {
+            return Ok(());
+        };
+
+        // positional format arguments after the string literal are not keys
+        let args = if allowed.contains(&"__expr") {
+            attr.format_args().0
+        } else {
+            attr.args().iter().collect()
+        };
+
+        args.into_iter()
+            .map(|nested| self.validate_nested(nested, allowed))
+            .combine()?;
+
+        Ok(())
+    }
+
+    fn validate_nested(&self, nested: &Arg, allowed: &[&str]) -> syn::Result<()> {
+        let key = self.key;
+        let name = nested
+            .path()
+            .get_ident()
+            .map(|i| i.to_string())
+            .unwrap_or_default();
+
+        if name.starts_with("__") && allowed.contains(&name.as_str()) {
+            return Ok(());
+        }
+
+        let forms: Vec<_> = allowed
+            .iter()
+            .map(|entry| Form::parse(entry))
+            .filter(|(n, _)| *n == name)
+            .map(|(_, form)| form)
+            .collect();
+
+        if forms.contains(&Form::of(nested)) {
+            return Ok(());
+        }
+
+        if !forms.is_empty() {
+            let expected: Vec<_> = forms.iter().map(|f| f.describe(key, &name)).collect();
+
+            return Err(nested
+                .path()
+                .error(&format!("expected {}", expected.join(" or "))));
+        }
+
+        let what = match name.as_str() {
+            "__value" => "a string literal".to_string(),
+            "__expr" => "an expression".to_string(),
+            _ => format!("`{key}({name})`"),
+        };
+
+        let elsewhere = self.positions(|p| names(p).contains(&name.as_str()));
+
+        if !elsewhere.is_empty() {
+            return Err(nested
+                .path()
+                .error(&format!("{what} can only be used on {elsewhere}")));
+        }
+
+        if name.starts_with("__") {
+            return Err(nested
+                .path()
+                .error(&format!("`{key}` does not accept {what}")));
+        }
+
+        Err(nested.path().error(&unknown(
+            &format!("unknown `{key}` argument `{name}`"),
+            &name,
+            &names(allowed),
+        )))
+    }
+
+    /// describe the positions whose allowed arguments match
+    fn positions(&self, matches: impl Fn(&[&str]) -> bool) -> String {
+        Position::ALL
+            .iter()
+            .filter(|p| self.at(**p).is_some_and(&matches))
+            .map(|p| p.describe())
+            .collect::<Vec<_>>()
+            .join(" or ")
+    }
+}
+
+/// append a "did you mean" hint to the message when
+/// one of the candidates is close enough to the name
+pub fn unknown(message: &str, name: &str, candidates: &[&str]) -> String {
+    let suggestion = candidates
+        .iter()
+        .filter(|c| !c.starts_with("__"))
+        .map(|c| (distance(name, c), *c))
+        .filter(|(d, c)| *d <= (c.len().max(name.len()) / 3).max(1))
+        .min_by_key(|(d, _)| *d);
+
+    match suggestion {
+        Some((_, c)) => format!("{message}; did you mean `{c}`?"),
+        None => message.to_string(),
+    }
+}
+
+/// levenshtein distance between two strings
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let next = (prev + usize::from(ca != *cb))
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            prev = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+
+    row[b.len()]
+}
@@ -0,0 +1,88 @@
+use syn::meta::ParseNestedMeta;
+
+use crate::core::Case;
+
+///
+/// ## SerdeAttrs
+/// the `#[serde(...)]` settings of a type or field that
+/// display follows, anything else (or anything malformed)
+/// is left for serde itself to accept or report
+///
+#[derive(Clone, Default)]
+pub struct SerdeAttrs {
+    pub rename: Option<String>,
+    pub rename_all: Option<Case>,
+    pub rename_all_fields: Option<Case>,
+    pub skip: bool,
+    pub skip_if: Option<syn::ExprPath>,
+}
+
+impl SerdeAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> Self {
+        let mut serde = Self::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+            let _ = attr.parse_nested_meta(|meta| serde.parse_meta(meta));
+        }
+
+        serde
+    }
+
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        let Some(ident) = meta.path.get_ident() else {
+            return ignore(meta);
+        };
+
+        match ident.to_string().as_str() {
+            "rename" => self.rename = serialize_name(&meta)?,
+            "rename_all" => {
+                self.rename_all = serialize_name(&meta)?.and_then(|name| Case::from_name(&name))
+            }
+            "rename_all_fields" => {
+                self.rename_all_fields =
+                    serialize_name(&meta)?.and_then(|name| Case::from_name(&name))
+            }
+            "skip" | "skip_serializing" => self.skip = true,
+            "skip_serializing_if" => {
+                let path: syn::LitStr = meta.value()?.parse()?;
+                self.skip_if = Some(path.parse()?);
+            }
+            _ => return ignore(meta),
+        }
+
+        Ok(())
+    }
+}
+
+/// the name from `rename = "a"` or `rename(serialize = "a")`
+fn serialize_name(meta: &ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(syn::Token![=]) {
+        let name: syn::LitStr = meta.value()?.parse()?;
+        return Ok(Some(name.value()));
+    }
+
+    let mut name = None;
+
+    meta.parse_nested_meta(|nested| {
+        let value: syn::LitStr = nested.value()?.parse()?;
+
+        if nested.path.is_ident("serialize") {
+            name = Some(value.value());
+        }
+
+        Ok(())
+    })?;
+
+    Ok(name)
+}
+
+/// consume the value of an argument display has no use for
+fn ignore(meta: ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.input.parse::<proc_macro2::Group>()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Bounds, Field},
+    params,
+};
+
+use super::structs::render_defaults;
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let ident = &args.input.ident;
+        let attributes = Attrs::parse(&args.input.attrs)?;
+        let mut bounds = Bounds::new(&args.input.generics, attributes.bound("default")?);
+        let mut marked = vec![];
+
+        for variant in args.data.variants.iter() {
+            let attrs = Attrs::parse(&variant.attrs)?;
+
+            if !attrs.get("default")?.is_empty() {
+                marked.push(variant);
+            }
+        }
+
+        let variant = match marked.as_slice() {
+            [] => {
+                return Err(ident.error(
+                    "no default variant; mark exactly one variant with `#[moxy(default)]`",
+                ));
+            }
+            [variant] => *variant,
+            [_, duplicate, ..] => {
+                return Err(duplicate.ident.error(
+                    "multiple default variants; only one variant may be `#[moxy(default)]`",
+                ));
+            }
+        };
+
+        let variant_ident = &variant.ident;
+        let fields: Vec<_> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| Field::parse(i, field))
+            .combine()?;
+
+        let value = if matches!(variant.fields, syn::Fields::Unit) {
+            quote!(Self::#variant_ident)
+        } else {
+            let defaults = render_defaults(&fields, &mut bounds);
+            quote! {
+                Self::#variant_ident {
+                    #(#defaults,)*
+                }
+            }
+        };
+
+        let generics = bounds.generics();
+        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+
+        Ok(quote! {
+            impl #impl_generics ::core::default::Default for #ident #type_generics #where_generics {
+                fn default() -> Self {
+                    #value
+                }
+            }
+        })
+    }
+}
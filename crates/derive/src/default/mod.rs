@@ -1,11 +1,21 @@
+mod enums;
 mod structs;
 
-use crate::{Render, params::Params};
+use crate::{Render, core::Schema, params::Params};
+
+pub const SCHEMA: Schema = Schema {
+    key: "default",
+    container: Some(&["bound ="]),
+    variant: Some(&["__flag"]),
+    field: Some(&["__flag", "__assign"]),
+};
 
 pub fn render(tokens: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
     let args = syn::parse(tokens)?;
+    SCHEMA.validate(&args)?;
 
     match args {
         Params::Struct(v) => structs::StructSyntax.render(v),
+        Params::Enum(v) => enums::EnumSyntax.render(v),
     }
 }
@@ -1,7 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::{Render, core::Field, params};
+use crate::{
+    Combine, Render,
+    core::{Attrs, Bounds, Field},
+    params,
+};
 
 #[derive(Clone, Default)]
 pub struct StructSyntax;
@@ -11,36 +15,22 @@ impl Render for StructSyntax {
 
     fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
         let ident = &args.input.ident;
-        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let attributes = Attrs::parse(&args.input.attrs)?;
         let fields: Vec<_> = args
             .data
             .fields
             .iter()
             .enumerate()
             .map(|(i, field)| Field::parse(i, field))
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
-        let defaults: Vec<_> = fields
-            .iter()
-            .map(|field| {
-                let fname = field.name();
-                let default = field.attrs().iter().find_map(|attr| {
-                    attr.args()
-                        .iter()
-                        .find(|arg| arg.path().is_ident("default"))
-                        .and_then(|arg| arg.as_value_tokens())
-                });
-
-                if let Some(value) = default {
-                    quote!(#fname: #value.into())
-                } else {
-                    quote!(#fname: ::std::default::Default::default())
-                }
-            })
-            .collect();
+        let mut bounds = Bounds::new(&args.input.generics, attributes.bound("default")?);
+        let defaults = render_defaults(&fields, &mut bounds);
+        let generics = bounds.generics();
+        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
 
         Ok(quote! {
-            impl #impl_generics ::std::default::Default for #ident #type_generics #where_generics {
+            impl #impl_generics ::core::default::Default for #ident #type_generics #where_generics {
                 fn default() -> Self {
                     Self {
                         #(#defaults,)*
@@ -50,3 +40,28 @@ impl Render for StructSyntax {
         })
     }
 }
+
+/// `name: value` initializers for each field, using `#[moxy(default = expr)]`
+/// when present and `Default::default()` otherwise, which the field's
+/// type parameters are then required to implement
+pub fn render_defaults(fields: &[Field], bounds: &mut Bounds) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let fname = field.name();
+            let default = field.attrs().iter().find_map(|attr| {
+                attr.args()
+                    .iter()
+                    .find(|arg| arg.path().is_ident("default"))
+                    .and_then(|arg| arg.as_value_tokens())
+            });
+
+            if let Some(value) = default {
+                quote!(#fname: #value.into())
+            } else {
+                bounds.require(field.ty(), &quote!(::core::default::Default));
+                quote!(#fname: ::core::default::Default::default())
+            }
+        })
+        .collect()
+}
@@ -1,11 +1,20 @@
 mod structs;
 
-use crate::{Render, params::Params};
+use crate::{Error, Render, core::Schema, params::Params};
+
+pub const SCHEMA: Schema = Schema {
+    key: "deref",
+    container: Some(&["bound ="]),
+    variant: None,
+    field: Some(&["__flag"]),
+};
 
 pub fn render(tokens: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
     let args = syn::parse(tokens)?;
+    SCHEMA.validate(&args)?;
 
     match args {
         Params::Struct(v) => structs::StructSyntax.render(v),
+        Params::Enum(v) => Err(v.input.error("enums are not supported")),
     }
 }
@@ -1,7 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::{Error, Render, core::Field, params};
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Bounds, Field},
+    params,
+};
 
 #[derive(Clone, Default)]
 pub struct StructSyntax;
@@ -11,14 +15,16 @@ impl Render for StructSyntax {
 
     fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
         let ident = &args.input.ident;
-        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let attributes = Attrs::parse(&args.input.attrs)?;
+        let generics = Bounds::new(&args.input.generics, attributes.bound("deref")?).generics();
+        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
         let fields: Vec<_> = args
             .data
             .fields
             .iter()
             .enumerate()
             .map(|(i, field)| Field::parse(i, field))
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
         let field = fields
             .iter()
@@ -32,7 +38,7 @@ impl Render for StructSyntax {
                 let field_ty = field.ty();
 
                 Ok(quote! {
-                    impl #impl_generics ::std::ops::Deref for #ident #type_generics #where_generics {
+                    impl #impl_generics ::core::ops::Deref for #ident #type_generics #where_generics {
                         type Target = #field_ty;
 
                         fn deref(&self) -> &Self::Target {
@@ -0,0 +1,192 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Bounds, Config, Field, SerdeAttrs},
+    params,
+};
+
+use super::{
+    Access, STYLES, formatter,
+    options::Options,
+    render_styled_impl,
+    structs::{render_bounds, render_fields, render_visible},
+};
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let formatter = formatter();
+        let ident = &args.input.ident;
+        let config = Config::load()?;
+        let attributes = Attrs::parse(&args.input.attrs)?;
+
+        if let Some(arg) = attributes.setting("display", "fields")? {
+            return Err(arg
+                .path()
+                .error("`display(fields)` is only supported on structs"));
+        }
+
+        if let Some(arg) = attributes.setting("display", "alias")? {
+            return Err(arg
+                .path()
+                .error("`display(alias)` is only supported on structs and variants"));
+        }
+
+        let enum_options = Options::parse(&attributes)?.configure(&config.display);
+
+        if let Some(spec) = &enum_options.custom_fmt {
+            return Err(spec.error(
+                "a format string is only supported on structs and variants, give each variant its own",
+            ));
+        }
+        let serde = SerdeAttrs::parse(&args.input.attrs);
+        let mut is_colored = enum_options.theme().is_some();
+        let mut bounds = Bounds::new(&args.input.generics, attributes.bound("display")?);
+        let mut styled_bounds = Bounds::new(&args.input.generics, attributes.bound("display")?);
+
+        // each variant's arm for `Display`, followed by its arm
+        // for every style when `display(styled)` is set
+        let variants = args
+            .data
+            .variants
+            .iter()
+            .map(|variant| -> syn::Result<Vec<TokenStream>> {
+                let variant_ident = &variant.ident;
+                let mut options =
+                    Options::parse(&Attrs::parse(&variant.attrs)?)?.inherit(&enum_options);
+                options.serde_case = SerdeAttrs::parse(&variant.attrs)
+                    .rename_all
+                    .or(serde.rename_all_fields);
+                let fields: Vec<_> = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| Field::parse(i, field))
+                    .combine()?;
+
+                let visible_fields = render_visible(&fields)?;
+
+                if let Some(bound) = options.bound() {
+                    render_bounds(&mut bounds, &visible_fields, &[bound])?;
+                }
+
+                let is_named = matches!(variant.fields, syn::Fields::Named(_));
+                let is_unit = matches!(variant.fields, syn::Fields::Unit);
+                let name_str = options.variant_name(variant_ident);
+
+                let pattern = match &variant.fields {
+                    syn::Fields::Named(_) => {
+                        let bindings = visible_fields.iter().map(|f| {
+                            let binding = Access::binding(f);
+                            quote!(#binding)
+                        });
+                        quote!(Self::#variant_ident { #(#bindings,)* .. })
+                    }
+                    syn::Fields::Unnamed(_) => {
+                        let bindings = fields.iter().map(|f| {
+                            if visible_fields.iter().any(|v| v.name() == f.name()) {
+                                let binding = Access::binding(f);
+                                quote!(#binding)
+                            } else {
+                                quote!(_)
+                            }
+                        });
+                        quote!(Self::#variant_ident(#(#bindings),*))
+                    }
+                    syn::Fields::Unit => quote!(Self::#variant_ident),
+                };
+
+                is_colored |= options.theme().is_some();
+                let render_arm = |options: &Options| -> syn::Result<TokenStream> {
+                    let body = render_fields(
+                        options,
+                        &visible_fields,
+                        is_named,
+                        is_unit,
+                        &name_str,
+                        Access::Binding,
+                    )?;
+
+                    Ok(quote! {
+                        #[allow(unused_variables)]
+                        #pattern => { #body }
+                    })
+                };
+
+                let mut arms = vec![render_arm(&options)?];
+
+                if enum_options.styled {
+                    render_bounds(
+                        &mut styled_bounds,
+                        &visible_fields,
+                        &[quote!(::core::fmt::Display), quote!(::core::fmt::Debug)],
+                    )?;
+
+                    for (_, style) in STYLES {
+                        arms.push(render_arm(&options.with_style(style))?);
+                    }
+                }
+
+                Ok(arms)
+            })
+            .combine()?;
+
+        let render_match = |i: usize| {
+            let arms = variants.iter().map(|arms| &arms[i]);
+
+            if variants.is_empty() {
+                quote! { match *self {} }
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        };
+
+        let prelude = if is_colored {
+            let colored = enum_options.runtime("colored");
+            quote! { extern crate alloc; use #colored::Colorize as _; }
+        } else {
+            quote!()
+        };
+
+        let styled = if enum_options.styled {
+            let bodies: Vec<_> = (1..=STYLES.len()).map(render_match).collect();
+
+            render_styled_impl(
+                &attributes.facade()?,
+                ident,
+                &styled_bounds.generics(),
+                &prelude,
+                &bodies,
+            )
+        } else {
+            quote!()
+        };
+
+        let generics = bounds.generics();
+        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+        let inner = render_match(0);
+        let track = config.track();
+
+        Ok(quote! {
+            impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_generics {
+                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #prelude
+                    #inner
+                }
+            }
+
+            #styled
+            #track
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{Error, core::Field};
+
+/// the field arguments that pick how a field is formatted
+const ARGS: &[&str] = &["fmt", "debug", "hex"];
+
+///
+/// ## Format
+/// a field displayed through its own format string, given
+/// with `fmt = "{:.2}"` or by the `debug` and `hex` shorthands
+/// for `"{:?}"` and `"{:#x}"`
+///
+#[derive(Clone)]
+pub struct Format {
+    pub spec: syn::LitStr,
+    pub bound: TokenStream,
+}
+
+impl Format {
+    /// the field's format, or `None` when it uses the one of its display style
+    pub fn parse(field: &Field) -> syn::Result<Option<Self>> {
+        let display = field.attrs().get("display")?;
+        let args: Vec<_> = display
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .flat_map(|attr| attr.args())
+            .filter(|arg| ARGS.iter().any(|name| arg.path().is_ident(name)))
+            .collect();
+
+        let Some(arg) = args.first() else {
+            return Ok(None);
+        };
+
+        if let Some(other) = args.get(1) {
+            return Err(other
+                .path()
+                .error("only one of `fmt`, `debug` and `hex` can be set on a field"));
+        }
+
+        if display
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .any(|attr| attr.exists("redact"))
+        {
+            return Err(arg
+                .path()
+                .error("a redacted field is displayed as its mask and cannot be formatted"));
+        }
+
+        let ident = arg.path().require_ident()?;
+        let spec = match ident.to_string().as_str() {
+            "fmt" => match arg.as_lit() {
+                Some(syn::Lit::Str(s)) => s.clone(),
+                _ => {
+                    return Err(arg
+                        .path()
+                        .error("expected a format string, e.g. `fmt = \"{:.2}\"`"));
+                }
+            },
+            name if !arg.is_flag() => {
+                return Err(arg
+                    .path()
+                    .error(&format!("expected a flag, e.g. `display({name})`")));
+            }
+            "debug" => syn::LitStr::new("{:?}", ident.span()),
+            _ => syn::LitStr::new("{:#x}", ident.span()),
+        };
+
+        let bound = bound(&spec)?;
+        Ok(Some(Self { spec, bound }))
+    }
+
+    /// the displayed value, given the expression reaching the field
+    pub fn value(&self, raw: &TokenStream) -> TokenStream {
+        let spec = &self.spec;
+        quote!(::core::format_args!(#spec, #raw))
+    }
+}
+
+/// the formatting trait used by the single placeholder of `spec`
+fn bound(spec: &syn::LitStr) -> syn::Result<TokenStream> {
+    let value = spec.value();
+    let mut chars = value.chars().peekable();
+    let mut placeholders = vec![];
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '{' => {
+                let placeholder: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                placeholders.push(placeholder);
+            }
+            '}' => return Err(spec.error("unmatched `}` in format string")),
+            _ => {}
+        }
+    }
+
+    let [placeholder] = placeholders.as_slice() else {
+        return Err(spec.error("expected a single `{}` placeholder for the field, e.g. \"{:.2}\""));
+    };
+
+    let (arg, format) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+
+    if !arg.is_empty() || format.contains(['$', '*']) {
+        return Err(
+            spec.error("the placeholder formats the field itself and cannot name other arguments")
+        );
+    }
+
+    Ok(match format.chars().last() {
+        Some('?') => quote!(::core::fmt::Debug),
+        Some('x') => quote!(::core::fmt::LowerHex),
+        Some('X') => quote!(::core::fmt::UpperHex),
+        Some('o') => quote!(::core::fmt::Octal),
+        Some('b') => quote!(::core::fmt::Binary),
+        Some('e') => quote!(::core::fmt::LowerExp),
+        Some('E') => quote!(::core::fmt::UpperExp),
+        _ => quote!(::core::fmt::Display),
+    })
+}
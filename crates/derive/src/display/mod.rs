@@ -1,13 +1,266 @@
+mod enums;
+mod format;
+mod options;
+mod redact;
 mod structs;
 #[cfg(feature = "color")]
 mod themes;
 
-use crate::{Render, params::Params};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Case, DisplayConfig, Field, Schema, local},
+    params::Params,
+};
+
+use format::Format;
+use options::Options;
+use redact::Redact;
+
+pub const SCHEMA: Schema = Schema {
+    key: "display",
+    container: Some(&[
+        "__value",
+        "__expr",
+        "debug",
+        "compact",
+        "keyvalue",
+        "map",
+        "json",
+        "pretty",
+        "pretty =",
+        "color",
+        "color =",
+        "alias =",
+        "rename_all =",
+        "ignore_serde",
+        "styled",
+        "fields",
+        "bound =",
+    ]),
+    variant: Some(&[
+        "__value",
+        "__expr",
+        "debug",
+        "compact",
+        "keyvalue",
+        "map",
+        "json",
+        "pretty",
+        "pretty =",
+        "color",
+        "color =",
+        "alias =",
+        "rename_all =",
+        "ignore_serde",
+    ]),
+    field: Some(&[
+        "skip",
+        "alias =",
+        "redact",
+        "redact(..)",
+        "fmt =",
+        "debug",
+        "hex",
+    ]),
+};
 
 pub fn render(tokens: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
     let args = syn::parse(tokens)?;
+    SCHEMA.validate(&args)?;
 
     match args {
         Params::Struct(v) => structs::StructSyntax.render(v),
+        Params::Enum(v) => enums::EnumSyntax.render(v),
+    }
+}
+
+/// the name `Display` gives each variant of an enum, so
+/// that other derives name the variants the same way. a unit
+/// variant with its own format string is named by the text it
+/// writes, which must not depend on any arguments
+pub fn variant_names(
+    input: &syn::DeriveInput,
+    data: &syn::DataEnum,
+    config: &DisplayConfig,
+) -> syn::Result<Vec<String>> {
+    let enum_options = Options::parse(&Attrs::parse(&input.attrs)?)?.configure(config);
+
+    data.variants
+        .iter()
+        .map(|variant| {
+            let options = Options::parse(&Attrs::parse(&variant.attrs)?)?.inherit(&enum_options);
+
+            match &options.custom_fmt {
+                Some(spec) if matches!(variant.fields, syn::Fields::Unit) => {
+                    literal(spec).filter(|_| options.exprs.is_empty()).ok_or_else(|| {
+                        spec.error("the format string of a unit variant must be plain text to be parsed back, e.g. \"not found\"")
+                    })
+                }
+                _ => Ok(options.variant_name(&variant.ident)),
+            }
+        })
+        .combine()
+}
+
+/// the text a format string writes when it has no
+/// placeholders, or `None` when it formats arguments
+fn literal(spec: &syn::LitStr) -> Option<String> {
+    let value = spec.value();
+    let mut chars = value.chars().peekable();
+    let mut text = String::new();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+            }
+            '{' | '}' => return None,
+            _ => {}
+        }
+
+        text.push(c);
+    }
+
+    Some(text)
+}
+
+///
+/// ## Access
+/// how generated code reaches a field's value,
+/// either through `self` or through a binding
+/// introduced by a `match` arm pattern
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// `self.name`
+    Field,
+    /// `Self::Variant { name, .. } => (*name)`
+    Binding,
+}
+
+impl Access {
+    /// the value displayed for the field, masked when it is marked
+    /// `#[moxy(display(redact))]` and formatted when it has its own format
+    pub fn value(&self, field: &Field) -> TokenStream {
+        let raw = self.raw(field);
+
+        if let Ok(Some(redact)) = Redact::parse(field) {
+            return redact.value(&raw);
+        }
+
+        match Format::parse(field) {
+            Ok(Some(format)) => format.value(&raw),
+            _ => raw,
+        }
+    }
+
+    /// the value serialized for the field, which is
+    /// the formatted text when it has its own format
+    #[allow(unused)]
+    pub fn serialized(&self, field: &Field) -> TokenStream {
+        let Ok(Some(format)) = Format::parse(field) else {
+            return self.value(field);
+        };
+
+        let spec = &format.spec;
+        let raw = self.raw(field);
+        quote!({
+            extern crate alloc;
+            alloc::format!(#spec, #raw)
+        })
+    }
+
+    /// the field's own value
+    pub fn raw(&self, field: &Field) -> TokenStream {
+        let fname = field.name();
+
+        match self {
+            Self::Field => quote!(self.#fname),
+            Self::Binding => {
+                let binding = Self::binding(field);
+                quote!((*#binding))
+            }
+        }
+    }
+
+    /// the identifier a field is bound to in a `match` arm pattern
+    pub fn binding(field: &Field) -> syn::Ident {
+        field.name().to_ident()
+    }
+}
+
+///
+/// ## Naming
+/// how a display style names fields, converting them to
+/// `case` and, when `serde` is set, following `#[serde(rename)]`
+///
+#[derive(Clone, Copy, Default)]
+pub struct Naming {
+    pub case: Option<Case>,
+    pub serde: bool,
+}
+
+impl Naming {
+    /// the field's `alias`, then its serde name, then its converted name
+    pub fn name(&self, field: &Field) -> syn::Result<String> {
+        if self.serde
+            && field.attrs().alias("display")?.is_none()
+            && let Some(rename) = &field.serde().rename
+        {
+            return Ok(rename.clone());
+        }
+
+        field.display_name(self.case)
+    }
+}
+
+/// the `Formatter` argument of the generated `fmt`, which
+/// fields and bindings named `f` cannot shadow
+pub fn formatter() -> syn::Ident {
+    local("f")
+}
+
+/// the facade's `Style` variants, with the display style each selects
+pub const STYLES: [(&str, Option<&str>); 5] = [
+    ("Default", None),
+    ("Debug", Some("debug")),
+    ("Compact", Some("compact")),
+    ("KeyValue", Some("keyvalue")),
+    ("Map", Some("map")),
+];
+
+/// `impl Styled`, picking the `fmt` body rendered for
+/// each of [`STYLES`] by the requested style
+pub fn render_styled_impl(
+    facade: &syn::Path,
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    prelude: &TokenStream,
+    bodies: &[TokenStream],
+) -> TokenStream {
+    let formatter = formatter();
+    let style = local("style");
+    let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+    let variants = STYLES.iter().map(|(name, _)| format_ident!("{}", name));
+
+    quote! {
+        impl #impl_generics #facade::Styled for #ident #type_generics #where_generics {
+            fn fmt_styled(
+                &self,
+                #style: #facade::Style,
+                #formatter: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                #prelude
+
+                match #style {
+                    #(#facade::Style::#variants => { #bodies })*
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::fmt::Display::fmt(self, #formatter),
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,243 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+
+use crate::{
+    Error,
+    core::{Arg, Attrs, Case, DisplayConfig},
+};
+
+///
+/// ## Options
+/// the display settings parsed from a
+/// `#[moxy(display(...))]` attribute on a
+/// struct, enum or enum variant
+///
+#[derive(Clone, Default)]
+pub struct Options {
+    pub custom_fmt: Option<syn::LitStr>,
+    pub exprs: Vec<syn::Expr>,
+    pub style: Option<String>,
+    /// `None` when unset, so a parent or `moxy.toml` decides
+    pub pretty: Option<bool>,
+    /// `None` when unset, and `Some(None)` when `color = false`
+    pub theme: Option<Option<String>>,
+    pub alias: Option<String>,
+    pub rename_all: Option<Case>,
+    pub ignore_serde: bool,
+    pub styled: bool,
+    pub fields: bool,
+    pub serde_case: Option<Case>,
+    pub config_case: Option<Case>,
+    pub krate: Option<syn::Path>,
+}
+
+impl Options {
+    pub fn parse(attrs: &Attrs) -> syn::Result<Self> {
+        let krate = attrs.crate_path()?;
+        let display = attrs.get("display")?;
+        let Some(attr) = display.iter().find_map(|arg| arg.as_attr()) else {
+            return Ok(Self {
+                krate,
+                ..Self::default()
+            });
+        };
+
+        let (args, exprs) = attr.format_args();
+
+        let custom_fmt = args.iter().find_map(|arg| {
+            if arg.path().is_ident("__value") {
+                arg.as_lit().and_then(|lit| match lit {
+                    syn::Lit::Str(s) => Some(s.clone()),
+                    _ => None,
+                })
+            } else {
+                None
+            }
+        });
+
+        let features = [
+            ("json", cfg!(feature = "json")),
+            ("color", cfg!(feature = "color")),
+        ];
+
+        for (feature, enabled) in features {
+            if let Some(arg) = args.iter().find(|arg| arg.path().is_ident(feature))
+                && !enabled
+                && !matches!(arg.as_lit(), Some(syn::Lit::Bool(b)) if !b.value)
+            {
+                return Err(arg.path().error(&format!(
+                    "`display({feature})` requires the `{feature}` feature of moxy"
+                )));
+            }
+        }
+
+        let styles: Vec<_> = args
+            .iter()
+            .filter_map(|arg| {
+                let name = arg.path().get_ident()?.to_string();
+                let is_style = matches!(
+                    name.as_str(),
+                    "debug" | "compact" | "keyvalue" | "map" | "json"
+                );
+                is_style.then_some((name, arg.path().clone()))
+            })
+            .collect();
+
+        if styles.len() > 1 {
+            return Err(syn::Error::new_spanned(
+                &styles[1].1,
+                "conflicting display styles; only one style may be specified",
+            ));
+        }
+
+        let pretty = args
+            .iter()
+            .find(|arg| arg.path().is_ident("pretty"))
+            .map(|arg| match arg.as_lit() {
+                None if arg.is_flag() => Ok(true),
+                Some(syn::Lit::Bool(b)) => Ok(b.value),
+                _ => Err(arg.path().error("expected `pretty` or `pretty = false`")),
+            })
+            .transpose()?;
+
+        let theme = args
+            .iter()
+            .find(|a| a.path().is_ident("color"))
+            .map(|arg| match arg.as_lit() {
+                None if arg.is_flag() => Ok(Some(String::new())),
+                Some(syn::Lit::Str(s)) => Ok(Some(s.value())),
+                Some(syn::Lit::Bool(b)) => Ok(b.value.then(String::new)),
+                _ => Err(arg
+                    .path()
+                    .error("expected `color`, a theme name or `color = false`")),
+            })
+            .transpose()?;
+
+        let rename_all = args
+            .iter()
+            .find(|arg| arg.path().is_ident("rename_all"))
+            .map(|arg| match arg.as_lit() {
+                Some(syn::Lit::Str(s)) => Case::parse(s),
+                _ => Err(arg
+                    .path()
+                    .error("expected a case, e.g. `rename_all = \"camelCase\"`")),
+            })
+            .transpose()?;
+
+        Ok(Self {
+            custom_fmt,
+            exprs: exprs
+                .iter()
+                .map(|arg| match arg {
+                    Arg::Expr(_, expr) => expr.clone(),
+                    other => syn::parse_quote!(#other),
+                })
+                .collect(),
+            style: styles.into_iter().next().map(|(name, _)| name),
+            pretty,
+            theme,
+            alias: attrs.alias("display")?,
+            rename_all,
+            ignore_serde: args.iter().any(|arg| arg.path().is_ident("ignore_serde")),
+            styled: args.iter().any(|arg| arg.path().is_ident("styled")),
+            fields: args.iter().any(|arg| arg.path().is_ident("fields")),
+            serde_case: None,
+            config_case: None,
+            krate,
+        })
+    }
+
+    /// fill in the style, pretty, color, case and serde settings
+    /// this variant leaves unset from its parent enum
+    pub fn inherit(mut self, parent: &Self) -> Self {
+        self.style = self.style.or_else(|| parent.style.clone());
+        self.pretty = self.pretty.or(parent.pretty);
+        self.theme = self.theme.or_else(|| parent.theme.clone());
+        self.rename_all = self.rename_all.or(parent.rename_all);
+        self.config_case = self.config_case.or(parent.config_case);
+        self.ignore_serde = self.ignore_serde || parent.ignore_serde;
+        self.krate = self.krate.or_else(|| parent.krate.clone());
+        self
+    }
+
+    /// fill in the style, pretty and color settings this struct or
+    /// enum leaves unset from `moxy.toml`, keeping its case apart
+    /// since a type's own `#[serde(rename_all)]` wins over it
+    pub fn configure(mut self, config: &DisplayConfig) -> Self {
+        self.style = self.style.or_else(|| config.style.clone());
+        self.pretty = self.pretty.or(Some(config.pretty));
+        self.theme = self.theme.or_else(|| Some(config.theme.clone()));
+        self.config_case = config.rename_all;
+        self
+    }
+
+    /// the case names are converted to, from `display(rename_all)`,
+    /// then `#[serde(rename_all)]` when `serde` is followed, then `moxy.toml`
+    pub fn case(&self, serde: bool) -> Option<Case> {
+        self.rename_all
+            .or(if serde { self.serde_case } else { None })
+            .or(self.config_case)
+    }
+
+    /// whether each field goes on its own line
+    pub fn pretty(&self) -> bool {
+        self.pretty.unwrap_or_default()
+    }
+
+    /// the color theme, `""` for the default one, or `None` when uncolored
+    pub fn theme(&self) -> Option<&str> {
+        self.theme.as_ref()?.as_deref()
+    }
+
+    /// the name displayed for a variant with these options, its
+    /// `alias` or else its own name converted to `rename_all`
+    pub fn variant_name(&self, ident: &syn::Ident) -> String {
+        self.alias.clone().unwrap_or_else(|| {
+            let name = ident.unraw().to_string();
+            self.case(false)
+                .map(|case| case.apply(&name))
+                .unwrap_or(name)
+        })
+    }
+
+    /// these options set to render `style`, or the
+    /// default style when `None`, in place of their own
+    pub fn with_style(&self, style: Option<&str>) -> Self {
+        Self {
+            custom_fmt: None,
+            exprs: vec![],
+            style: style.map(String::from),
+            ..self.clone()
+        }
+    }
+
+    /// the trait each displayed field must implement, or `None`
+    /// for custom format strings where it cannot be known
+    pub fn bound(&self) -> Option<TokenStream> {
+        if self.custom_fmt.is_some() {
+            return None;
+        }
+
+        Some(match self.style.as_deref() {
+            Some("debug") => quote!(::core::fmt::Debug),
+            Some("json") => {
+                let serde = self.runtime("serde");
+                quote!(#serde::Serialize)
+            }
+            _ => quote!(::core::fmt::Display),
+        })
+    }
+
+    /// the path to a runtime dependency, resolved through the
+    /// `moxy` facade when `#[moxy(crate = ...)]` is set
+    #[allow(unused)]
+    pub fn runtime(&self, name: &str) -> TokenStream {
+        let ident = format_ident!("{}", name);
+
+        match &self.krate {
+            Some(path) => quote!(#path::__private::#ident),
+            None => quote!(::#ident),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    Error,
+    core::{Field, local, unknown},
+};
+
+const ARGS: &[&str] = &["mask", "partial"];
+const MASK: &str = "****";
+
+///
+/// ## Redact
+/// a field marked `#[moxy(display(redact))]`, displayed as
+/// its `mask` followed by the last `partial` characters of
+/// its value, if any, in place of the value itself
+///
+#[derive(Clone)]
+pub struct Redact {
+    pub mask: String,
+    pub partial: Option<usize>,
+}
+
+impl Redact {
+    /// the field's `redact` settings, or `None` when it is displayed as is
+    pub fn parse(field: &Field) -> syn::Result<Option<Self>> {
+        let display = field.attrs().get("display")?;
+        let Some(arg) = display
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .find_map(|attr| attr.get("redact"))
+        else {
+            return Ok(None);
+        };
+
+        let mut redact = Self {
+            mask: MASK.to_string(),
+            partial: None,
+        };
+
+        let Some(attr) = arg.as_attr() else {
+            if !arg.is_flag() {
+                return Err(arg.path().error(
+                    "expected `redact`, `redact(mask = \"...\")` or `redact(partial = 4)`",
+                ));
+            }
+
+            return Ok(Some(redact));
+        };
+
+        for arg in attr.args() {
+            let name = arg
+                .path()
+                .get_ident()
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+
+            match (name.as_str(), arg.as_lit()) {
+                ("mask", Some(syn::Lit::Str(s))) => redact.mask = s.value(),
+                ("mask", _) => {
+                    return Err(arg
+                        .path()
+                        .error("expected a string, e.g. `mask = \"****\"`"));
+                }
+                ("partial", Some(syn::Lit::Int(n))) => {
+                    let n = n.base10_parse::<usize>()?;
+
+                    if n == 0 {
+                        return Err(arg.path().error("`partial` must be at least 1"));
+                    }
+
+                    if !cfg!(feature = "alloc") {
+                        return Err(arg
+                            .path()
+                            .error("`redact(partial)` requires the `alloc` feature of moxy"));
+                    }
+
+                    redact.partial = Some(n);
+                }
+                ("partial", _) => {
+                    return Err(arg
+                        .path()
+                        .error("expected the number of characters to keep, e.g. `partial = 4`"));
+                }
+                _ => {
+                    return Err(arg.path().error(&unknown(
+                        &format!("unknown argument `{name}` for `redact`"),
+                        &name,
+                        ARGS,
+                    )));
+                }
+            }
+        }
+
+        Ok(Some(redact))
+    }
+
+    /// the trait the field must implement, `Display` when part
+    /// of its value is kept and nothing when it is fully masked
+    pub fn bound(&self) -> Option<TokenStream> {
+        self.partial.map(|_| quote!(::core::fmt::Display))
+    }
+
+    /// the displayed value, given the expression reaching the field
+    pub fn value(&self, raw: &TokenStream) -> TokenStream {
+        let mask = &self.mask;
+        // the field is still read, so masking
+        // it leaves no unused field behind
+        let Some(partial) = self.partial else {
+            return quote!({
+                let _ = &#raw;
+                #mask
+            });
+        };
+
+        let (value, tail) = (local("__value"), local("__tail"));
+        let nth = partial - 1;
+
+        // the whole value stays masked when it is no
+        // longer than the characters it would keep
+        quote!({
+            extern crate alloc;
+            let #value = alloc::string::ToString::to_string(&#raw);
+            let #tail = match #value.char_indices().rev().nth(#nth) {
+                ::core::option::Option::Some((i, _)) if i > 0 => &#value[i..],
+                _ => "",
+            };
+            alloc::format!("{}{}", #mask, #tail)
+        })
+    }
+}
@@ -2,11 +2,15 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    Render,
-    core::{Attrs, Field, FieldName},
+    Combine, Render,
+    core::{Attrs, Bounds, Config, Field, FieldName, SerdeAttrs, local},
     params,
 };
 
+use super::{
+    Access, Format, Naming, Redact, STYLES, formatter, options::Options, render_styled_impl,
+};
+
 #[derive(Clone, Default)]
 pub struct StructSyntax;
 
@@ -14,585 +18,625 @@ impl Render for StructSyntax {
     type Args = params::StructParams;
 
     fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let formatter = formatter();
         let ident = &args.input.ident;
-        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let config = Config::load()?;
         let attributes = Attrs::parse(&args.input.attrs)?;
-        let display = attributes.get("display")?;
-        let display_attr = display.iter().find_map(|arg| arg.as_attr());
-        let custom_fmt = display_attr.and_then(|attr| {
-            attr.args().iter().find_map(|arg| {
-                if arg.path().is_ident("__value") {
-                    arg.as_lit().and_then(|lit| match lit {
-                        syn::Lit::Str(s) => Some(s),
-                        _ => None,
-                    })
-                } else {
-                    None
-                }
-            })
-        });
-
-        let style = if let Some(attr) = display_attr {
-            let styles: Vec<_> = attr
-                .args()
-                .iter()
-                .filter_map(|arg| {
-                    let name = arg.path().get_ident()?.to_string();
-                    let is_style =
-                        matches!(name.as_str(), "debug" | "compact" | "keyvalue" | "map")
-                            || cfg!(feature = "json") && name == "json";
-                    is_style.then_some((name, arg.path().clone()))
-                })
-                .collect();
-            if styles.len() > 1 {
-                return Err(syn::Error::new_spanned(
-                    &styles[1].1,
-                    "conflicting display styles; only one style may be specified",
-                ));
-            }
-            styles.into_iter().next().map(|(name, _)| name)
-        } else {
-            None
-        };
-
-        let pretty = display_attr
-            .map(|attr| attr.exists("pretty"))
-            .unwrap_or(false);
-
-        let theme: Option<String> = if cfg!(feature = "color") {
-            display_attr.and_then(|attr| {
-                let arg = attr.args().iter().find(|a| a.path().is_ident("color"))?;
-                match arg.as_lit() {
-                    Some(syn::Lit::Str(s)) => Some(s.value()),
-                    _ => Some(String::new()),
-                }
-            })
-        } else {
-            None
-        };
-
-        let alias = display_attr.and_then(|attr| {
-            attr.args().iter().find_map(|arg| {
-                if arg.path().is_ident("alias") {
-                    arg.as_lit().and_then(|lit| match lit {
-                        syn::Lit::Str(s) => Some(s.value()),
-                        _ => None,
-                    })
-                } else {
-                    None
-                }
-            })
-        });
-
+        let mut options = Options::parse(&attributes)?.configure(&config.display);
+        options.serde_case = SerdeAttrs::parse(&args.input.attrs).rename_all;
         let fields: Vec<_> = args
             .data
             .fields
             .iter()
             .enumerate()
             .map(|(i, field)| Field::parse(i, field))
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
 
-        let visible_fields = fields
-            .iter()
-            .map(|f| -> syn::Result<Option<&Field>> {
-                let field_display = f.attrs().get("display")?;
-                let field_attr = field_display.iter().find_map(|a| a.as_attr());
-                Ok(if field_attr.map(|a| a.exists("skip")).unwrap_or(false) {
-                    None
-                } else {
-                    Some(f)
-                })
-            })
-            .collect::<syn::Result<Vec<_>>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
+        let visible_fields = render_visible(&fields)?;
+        let mut bounds = Bounds::new(&args.input.generics, attributes.bound("display")?);
 
+        if let Some(bound) = options.bound() {
+            render_bounds(&mut bounds, &visible_fields, &[bound])?;
+        }
+
+        let generics = bounds.generics();
+        let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
         let is_named = matches!(args.data.fields, syn::Fields::Named(_));
         let is_unit = matches!(args.data.fields, syn::Fields::Unit);
-        let name_str = alias.unwrap_or_else(|| ident.to_string());
-        let fmt_exprs: Vec<&syn::Expr> = display_attr
-            .map(|attr| attr.args().iter().filter_map(|arg| arg.as_expr()).collect())
-            .unwrap_or_default();
-
-        let inner = if is_unit || visible_fields.is_empty() {
-            quote! { ::std::write!(f, #name_str) }
-        } else if let Some(fmt_str) = custom_fmt {
-            render_custom_fmt(&visible_fields, is_named, &fmt_str, &fmt_exprs)
-        } else if let Some(mode) = style {
-            render_style(
-                &mode,
-                &visible_fields,
-                is_named,
-                &name_str,
-                pretty,
-                theme.as_deref(),
-            )?
+        let name_str = options.alias.clone().unwrap_or_else(|| ident.to_string());
+        let inner = render_fields(
+            &options,
+            &visible_fields,
+            is_named,
+            is_unit,
+            &name_str,
+            Access::Field,
+        )?;
+
+        let prelude = if cfg!(feature = "color") && options.theme().is_some() {
+            let colored = options.runtime("colored");
+            quote! { extern crate alloc; use #colored::Colorize as _; }
         } else {
-            render_default(
-                &visible_fields,
-                is_named,
-                &name_str,
-                pretty,
-                theme.as_deref(),
-            )?
+            quote!()
         };
 
-        #[cfg(feature = "color")]
-        let body = if theme.is_some() {
-            quote! { use ::colored::Colorize as _; #inner }
+        let fields_impl = if options.fields {
+            render_fields_impl(&attributes.facade()?, &args.input, &fields, &options)?
         } else {
-            inner
+            quote!()
         };
-        #[cfg(not(feature = "color"))]
-        let body = inner;
+
+        let styled = if options.styled {
+            let styles = STYLES
+                .iter()
+                .map(|(_, style)| {
+                    render_fields(
+                        &options.with_style(*style),
+                        &visible_fields,
+                        is_named,
+                        is_unit,
+                        &name_str,
+                        Access::Field,
+                    )
+                })
+                .combine()?;
+
+            let mut styled_bounds = Bounds::new(&args.input.generics, attributes.bound("display")?);
+
+            render_bounds(
+                &mut styled_bounds,
+                &visible_fields,
+                &[quote!(::core::fmt::Display), quote!(::core::fmt::Debug)],
+            )?;
+
+            render_styled_impl(
+                &attributes.facade()?,
+                ident,
+                &styled_bounds.generics(),
+                &prelude,
+                &styles,
+            )
+        } else {
+            quote!()
+        };
+
+        let track = config.track();
 
         Ok(quote! {
-            impl #impl_generics ::std::fmt::Display for #ident #type_generics #where_generics {
-                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                    #body
+            impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_generics {
+                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #prelude
+                    #inner
                 }
             }
+
+            #fields_impl
+            #styled
+            #track
         })
     }
 }
 
-#[cfg(feature = "color")]
-fn themed_tokens(theme: &str) -> (TokenStream, TokenStream, TokenStream, TokenStream) {
-    let t = super::themes::get(theme);
-    let nc = t.name();
-    let fc = t.field();
-    let vc = t.value();
-    let pc = t.punct();
-    let (nr, ng, nb) = (nc.r, nc.g, nc.b);
-    let (fr, fg, fb) = (fc.r, fc.g, fc.b);
-    let (vr, vg, vb) = (vc.r, vc.g, vc.b);
-    let (pr, pg, pb) = (pc.r, pc.g, pc.b);
-    (
-        quote! { .truecolor(#nr, #ng, #nb).bold() },
-        quote! { .truecolor(#fr, #fg, #fb) },
-        quote! { .truecolor(#vr, #vg, #vb) },
-        quote! { .truecolor(#pr, #pg, #pb) },
-    )
+/// `impl Fields`, listing every field's declared and displayed name
+fn render_fields_impl(
+    facade: &syn::Path,
+    input: &syn::DeriveInput,
+    fields: &[Field],
+    options: &Options,
+) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_generics) = input.generics.split_for_impl();
+    let names = fields.iter().map(|f| {
+        let name = f.name().unraw();
+        quote!(#name)
+    });
+    let aliases: Vec<_> = fields
+        .iter()
+        .map(|f| -> syn::Result<TokenStream> {
+            let alias = f.display_name(options.case(false))?;
+            Ok(quote!(#alias))
+        })
+        .combine()?;
+
+    Ok(quote! {
+        impl #impl_generics #facade::Fields for #ident #type_generics #where_generics {
+            const FIELDS: &'static [&'static str] = &[#(#names),*];
+            const ALIASES: &'static [&'static str] = &[#(#aliases),*];
+        }
+    })
 }
 
-fn render_default(
+/// the fields that are not marked `#[moxy(display(skip))]`
+pub fn render_visible(fields: &[Field]) -> syn::Result<Vec<&Field>> {
+    Ok(fields
+        .iter()
+        .map(|f| -> syn::Result<Option<&Field>> {
+            let field_display = f.attrs().get("display")?;
+            let field_attr = field_display.iter().find_map(|a| a.as_attr());
+            Ok(if field_attr.map(|a| a.exists("skip")).unwrap_or(false) {
+                None
+            } else {
+                Redact::parse(f)?;
+                Format::parse(f)?;
+                Some(f)
+            })
+        })
+        .combine()?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// require each of `traits` for every displayed field, except redacted
+/// fields, which only need what their mask shows of them, and fields
+/// with their own format, which need the trait it formats them with
+pub fn render_bounds(
+    bounds: &mut Bounds,
+    fields: &[&Field],
+    traits: &[TokenStream],
+) -> syn::Result<()> {
+    for field in fields {
+        if let Some(redact) = Redact::parse(field)? {
+            if let Some(bound) = redact.bound() {
+                bounds.require(field.ty(), &bound);
+            }
+        } else if let Some(format) = Format::parse(field)? {
+            bounds.require(field.ty(), &format.bound);
+        } else {
+            for bound in traits {
+                bounds.require(field.ty(), bound);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// render the `fmt` body for a set of fields according to
+/// the given display options
+pub fn render_fields(
+    options: &Options,
     fields: &[&Field],
     is_named: bool,
+    is_unit: bool,
     name: &str,
-    pretty: bool,
-    theme: Option<&str>,
+    access: Access,
 ) -> syn::Result<TokenStream> {
-    let _ = &theme;
-    #[cfg(feature = "color")]
-    if let Some(t) = theme {
-        let (nc, fc, vc, pc) = themed_tokens(t);
-        let mut fmt = String::new();
-        let mut args: Vec<TokenStream> = Vec::new();
-
-        if is_named {
-            fmt.push_str("{}{}");
-            args.push(quote! { #name #nc });
-            args.push(if pretty {
-                quote! { " {\n" #pc }
-            } else {
-                quote! { " { " #pc }
-            });
+    let formatter = formatter();
+    let exprs: Vec<_> = options.exprs.iter().collect();
+
+    if let Some(fmt_str) = &options.custom_fmt {
+        Ok(render_custom_fmt(fields, is_named, fmt_str, &exprs, access))
+    } else if is_unit || fields.is_empty() {
+        Ok(quote! { #formatter.write_str(#name) })
+    } else if let Some(mode) = &options.style {
+        render_style(mode, fields, is_named, name, options, access)
+    } else {
+        render_default(
+            fields,
+            is_named,
+            name,
+            options.pretty(),
+            options.theme(),
+            Naming {
+                case: options.case(false),
+                serde: false,
+            },
+            access,
+        )
+    }
+}
 
-            for (i, f) in fields.iter().enumerate() {
-                let fname = f.name();
-                let dname = f.display_name()?;
-                if pretty {
-                    fmt.push_str("    {}{}{}{}");
-                } else {
-                    fmt.push_str("{}{}{}");
-                    if i + 1 < fields.len() {
-                        fmt.push_str("{}");
-                    }
-                }
-                args.push(quote! { #dname #fc });
-                args.push(quote! { ": " #pc });
-                args.push(quote! { ::std::format!("{}", self.#fname) #vc });
-                if pretty {
-                    args.push(quote! { ",\n" #pc });
-                } else if i + 1 < fields.len() {
-                    args.push(quote! { ", " #pc });
-                }
-            }
+///
+/// ## Piece
+/// a run of output written by a single `write!`,
+/// its format string and the arguments it formats
+///
+#[derive(Clone, Default)]
+struct Piece {
+    fmt: String,
+    args: Vec<TokenStream>,
+}
 
-            fmt.push_str("{}");
-            args.push(if pretty {
-                quote! { "}" #pc }
-            } else {
-                quote! { " }" #pc }
-            });
-        } else {
-            fmt.push_str("{}{}");
-            args.push(quote! { #name #nc });
-            args.push(if pretty {
-                quote! { "(\n" #pc }
-            } else {
-                quote! { "(" #pc }
-            });
+impl Piece {
+    /// literal text, its braces escaped
+    fn text(mut self, text: &str) -> Self {
+        self.fmt
+            .push_str(&text.replace('{', "{{").replace('}', "}}"));
+        self
+    }
 
-            for (i, f) in fields.iter().enumerate() {
-                let fname = f.name();
-                if pretty {
-                    fmt.push_str("    {}{}");
-                    args.push(quote! { ::std::format!("{}", self.#fname) #vc });
-                    args.push(quote! { ",\n" #pc });
-                } else {
-                    fmt.push_str("{}");
-                    args.push(quote! { ::std::format!("{}", self.#fname) #vc });
-                    if i + 1 < fields.len() {
-                        fmt.push_str("{}");
-                        args.push(quote! { ", " #pc });
-                    }
-                }
-            }
+    /// an argument, formatted with `spec`, e.g. `{:?}`
+    fn arg(mut self, spec: &str, arg: TokenStream) -> Self {
+        self.fmt.push_str(spec);
+        self.args.push(arg);
+        self
+    }
 
-            fmt.push_str("{}");
-            args.push(quote! { ")" #pc });
-        }
+    fn append(mut self, other: &Self) -> Self {
+        self.fmt.push_str(&other.fmt);
+        self.args.extend(other.args.iter().cloned());
+        self
+    }
 
-        return Ok(quote! { ::std::write!(f, #fmt, #(#args),*) });
+    fn is_empty(&self) -> bool {
+        self.fmt.is_empty()
     }
 
-    let mut fmt = String::new();
-    let mut args = Vec::new();
+    /// the `write!` of this piece, returning a `fmt::Result`
+    fn write(&self) -> TokenStream {
+        let formatter = formatter();
+        let (fmt, args) = (&self.fmt, &self.args);
+        quote!(::core::write!(#formatter, #fmt #(, #args)*))
+    }
+}
 
-    if is_named {
-        fmt.push_str(name);
-        fmt.push_str(if pretty { " {{\n" } else { " {{ " });
+///
+/// ## Paint
+/// how the parts of a display style are written, as plain
+/// text or colored by the theme of `display(color)`
+///
+#[derive(Clone, Default)]
+struct Paint {
+    /// the colors of names, fields, values and punctuation
+    colors: Option<[TokenStream; 4]>,
+}
 
-        for (i, f) in fields.iter().enumerate() {
-            let fname = f.name();
-            if pretty {
-                fmt.push_str("    ");
-                fmt.push_str(&f.display_name()?);
-                fmt.push_str(": {},\n");
-            } else {
-                fmt.push_str(&f.display_name()?);
-                fmt.push_str(": {}");
-                if i + 1 < fields.len() {
-                    fmt.push_str(", ");
-                }
-            }
-            args.push(quote! { self.#fname });
+impl Paint {
+    fn new(theme: Option<&str>) -> Self {
+        let _ = &theme;
+        #[cfg(feature = "color")]
+        if let Some(theme) = theme {
+            let t = super::themes::get(theme);
+            let color = |c: colored::CustomColor| {
+                let (r, g, b) = (c.r, c.g, c.b);
+                quote! { .truecolor(#r, #g, #b) }
+            };
+            let name = color(t.name());
+
+            return Self {
+                colors: Some([
+                    quote! { #name.bold() },
+                    color(t.field()),
+                    color(t.value()),
+                    color(t.punct()),
+                ]),
+            };
         }
 
-        fmt.push_str(if pretty { "}}" } else { " }}" });
-    } else {
-        fmt.push_str(name);
-        fmt.push_str(if pretty { "(\n" } else { "(" });
+        Self::default()
+    }
 
-        for (i, f) in fields.iter().enumerate() {
-            let fname = f.name();
-            if pretty {
-                fmt.push_str("    {},\n");
-            } else {
-                fmt.push_str("{}");
-                if i + 1 < fields.len() {
-                    fmt.push_str(", ");
-                }
+    fn paint(&self, piece: Piece, text: &str, i: usize) -> Piece {
+        match &self.colors {
+            _ if text.is_empty() => piece,
+            Some(colors) => {
+                let color = &colors[i];
+                piece.arg("{}", quote! { #text #color })
             }
-            args.push(quote! { self.#fname });
+            None => piece.text(text),
         }
+    }
 
-        fmt.push(')');
+    fn name(&self, piece: Piece, text: &str) -> Piece {
+        self.paint(piece, text, 0)
     }
 
-    Ok(quote! { ::std::write!(f, #fmt, #(#args),*) })
-}
+    fn field(&self, piece: Piece, text: &str) -> Piece {
+        self.paint(piece, text, 1)
+    }
 
-fn render_debug(
-    fields: &[&Field],
-    is_named: bool,
-    name: &str,
-    pretty: bool,
-    theme: Option<&str>,
-) -> syn::Result<TokenStream> {
-    let _ = &theme;
-    #[cfg(feature = "color")]
-    if let Some(t) = theme {
-        let (nc, fc, vc, pc) = themed_tokens(t);
-        let mut fmt = String::new();
-        let mut args: Vec<TokenStream> = Vec::new();
-
-        fmt.push_str("{}");
-        args.push(quote! { #name #nc });
-
-        if is_named {
-            fmt.push_str("{}");
-            args.push(if pretty {
-                quote! { " {\n" #pc }
-            } else {
-                quote! { " { " #pc }
-            });
+    fn punct(&self, piece: Piece, text: &str) -> Piece {
+        self.paint(piece, text, 3)
+    }
 
-            for (i, f) in fields.iter().enumerate() {
-                let fname = f.name();
-                let dname = f.display_name()?;
-                if pretty {
-                    fmt.push_str("    {}{}{}{}");
-                } else {
-                    fmt.push_str("{}{}{}");
-                    if i + 1 < fields.len() {
-                        fmt.push_str("{}");
-                    }
-                }
-                args.push(quote! { #dname #fc });
-                args.push(quote! { ": " #pc });
-                args.push(quote! { ::std::format!("{:?}", self.#fname) #vc });
-                if pretty {
-                    args.push(quote! { ",\n" #pc });
-                } else if i + 1 < fields.len() {
-                    args.push(quote! { ", " #pc });
-                }
+    /// a field's value, formatted with `spec`
+    fn value(&self, piece: Piece, spec: &str, value: TokenStream) -> Piece {
+        match &self.colors {
+            Some(colors) => {
+                let color = &colors[2];
+                piece.arg("{}", quote! { alloc::format!(#spec, #value) #color })
             }
+            None => piece.arg(spec, value),
+        }
+    }
+}
 
-            fmt.push_str("{}");
-            args.push(if pretty {
-                quote! { "}" #pc }
-            } else {
-                quote! { " }" #pc }
-            });
-        } else {
-            fmt.push_str("{}");
-            args.push(if pretty {
-                quote! { "(\n" #pc }
-            } else {
-                quote! { "(" #pc }
-            });
+///
+/// ## Layout
+/// the output of a display style, split into the parts
+/// written before, between and after the field entries
+///
+struct Layout {
+    open: Piece,
+    entries: Vec<Entry>,
+    separator: Piece,
+    close: Piece,
+}
 
-            for (i, f) in fields.iter().enumerate() {
-                let fname = f.name();
-                if pretty {
-                    fmt.push_str("    {}{}");
-                    args.push(quote! { ::std::format!("{:?}", self.#fname) #vc });
-                    args.push(quote! { ",\n" #pc });
-                } else {
-                    fmt.push_str("{}");
-                    args.push(quote! { ::std::format!("{:?}", self.#fname) #vc });
-                    if i + 1 < fields.len() {
-                        fmt.push_str("{}");
-                        args.push(quote! { ", " #pc });
-                    }
-                }
-            }
+/// a field's part of the output, with the check that leaves it out
+struct Entry {
+    piece: Piece,
+    skip: Option<TokenStream>,
+}
 
-            fmt.push_str("{}");
-            args.push(quote! { ")" #pc });
+impl Entry {
+    fn new(field: &Field, piece: Piece, naming: Naming, access: Access) -> Self {
+        Self {
+            piece,
+            skip: render_skip(field, naming, access),
         }
-
-        return Ok(quote! { ::std::write!(f, #fmt, #(#args),*) });
     }
+}
 
-    if !pretty {
-        if is_named {
-            let entries = fields
-                .iter()
-                .map(|f| {
-                    let fname = f.name();
-                    let fname_str = f.display_name()?;
-                    Ok(quote! { .field(#fname_str, &self.#fname) })
-                })
-                .collect::<syn::Result<Vec<_>>>()?;
+impl Layout {
+    /// a single `write!` when every entry is always written, and
+    /// otherwise a `write!` per entry, gated by its
+    /// `skip_serializing_if`, so the output grows with each field
+    fn render(self) -> TokenStream {
+        if self.entries.iter().all(|e| e.skip.is_none()) {
+            let mut piece = self.open;
+
+            for (i, entry) in self.entries.iter().enumerate() {
+                if i > 0 {
+                    piece = piece.append(&self.separator);
+                }
 
-            return Ok(quote! {
-                f.debug_struct(#name)
-                    #(#entries)*
-                    .finish()
-            });
+                piece = piece.append(&entry.piece);
+            }
+
+            return piece.append(&self.close).write();
         }
 
-        let entries: Vec<_> = fields
-            .iter()
-            .map(|f| {
-                let fname = f.name();
-                quote! { .field(&self.#fname) }
-            })
-            .collect();
+        // separators only go between the entries that are written
+        let first = local("__first");
+        let separate = !self.separator.is_empty();
+        let entries = self.entries.iter().map(|entry| {
+            let write = entry.piece.write();
+            let separator = separate.then(|| {
+                let separator = self.separator.write();
+                quote! {
+                    if !#first {
+                        #separator?;
+                    }
+                    #first = false;
+                }
+            });
 
-        return Ok(quote! {
-            f.debug_tuple(#name)
-                #(#entries)*
-                .finish()
+            render_gate(
+                entry.skip.as_ref(),
+                quote! {
+                    #separator
+                    #write?;
+                },
+            )
         });
-    }
-
-    let mut fmt = String::new();
-    let mut args = Vec::new();
-
-    fmt.push_str(name);
-
-    if is_named {
-        fmt.push_str(" {{\n");
-
-        for f in fields.iter() {
-            let fname = f.name();
-            fmt.push_str("    ");
-            fmt.push_str(&f.display_name()?);
-            fmt.push_str(": {:?},\n");
-            args.push(quote! { self.#fname });
-        }
 
-        fmt.push_str("}}");
-    } else {
-        fmt.push_str("(\n");
+        let open = (!self.open.is_empty()).then(|| {
+            let open = self.open.write();
+            quote!(#open?;)
+        });
+        let flag = separate.then(|| quote!(let mut #first = true;));
+        let read = separate.then(|| quote!(let _ = #first;));
+        let close = match self.close.is_empty() {
+            true => quote!(::core::result::Result::Ok(())),
+            false => self.close.write(),
+        };
 
-        for f in fields.iter() {
-            let fname = f.name();
-            fmt.push_str("    {:?},\n");
-            args.push(quote! { self.#fname });
+        quote! {
+            #open
+            #flag
+            #(#entries)*
+            #read
+            #close
         }
-
-        fmt.push(')');
     }
-
-    Ok(quote! { ::std::write!(f, #fmt, #(#args),*) })
 }
 
-fn render_compact(fields: &[&Field]) -> syn::Result<TokenStream> {
-    let mut fmt = String::new();
-    let mut args = Vec::new();
-
-    for (i, f) in fields.iter().enumerate() {
-        let fname = f.name();
-        fmt.push_str("{}");
-
-        if i + 1 < fields.len() {
-            fmt.push(' ');
-        }
+/// the check leaving a field out of a style that follows
+/// serde, when it has `#[serde(skip_serializing_if = "...")]`
+fn render_skip(field: &Field, naming: Naming, access: Access) -> Option<TokenStream> {
+    let path = field.serde().skip_if.as_ref().filter(|_| naming.serde)?;
+    let raw = access.raw(field);
+    Some(quote!(#path(&#raw)))
+}
 
-        args.push(quote! { self.#fname });
+/// `body`, only run when `skip` doesn't hold
+fn render_gate(skip: Option<&TokenStream>, body: TokenStream) -> TokenStream {
+    match skip {
+        Some(skip) => quote! {
+            if !#skip {
+                #body
+            }
+        },
+        None => body,
     }
-
-    Ok(quote! { ::std::write!(f, #fmt, #(#args),*) })
 }
 
-fn render_keyvalue(
+fn render_default(
     fields: &[&Field],
+    is_named: bool,
+    name: &str,
     pretty: bool,
     theme: Option<&str>,
+    naming: Naming,
+    access: Access,
 ) -> syn::Result<TokenStream> {
-    let _ = &theme;
-    #[cfg(feature = "color")]
-    if let Some(t) = theme {
-        let (_nc, fc, vc, pc) = themed_tokens(t);
-        let sep = if pretty { "\n" } else { " " };
-        let mut fmt = String::new();
-        let mut args: Vec<TokenStream> = Vec::new();
-
-        for (i, f) in fields.iter().enumerate() {
-            let fname = f.name();
-            let dname = f.display_name()?;
-            fmt.push_str("{}{}{}");
-            args.push(quote! { #dname #fc });
-            args.push(quote! { "=" #pc });
-            args.push(quote! { ::std::format!("{}", self.#fname) #vc });
-
-            if i + 1 < fields.len() {
-                fmt.push_str(sep);
-            }
-        }
+    render_bracketed(
+        fields,
+        is_named,
+        Some(name),
+        "{}",
+        pretty,
+        theme,
+        naming,
+        access,
+    )
+}
 
-        return Ok(quote! { ::std::write!(f, #fmt, #(#args),*) });
+fn render_debug(
+    fields: &[&Field],
+    is_named: bool,
+    name: &str,
+    pretty: bool,
+    theme: Option<&str>,
+    naming: Naming,
+    access: Access,
+) -> syn::Result<TokenStream> {
+    if pretty || theme.is_some() {
+        return render_bracketed(
+            fields,
+            is_named,
+            Some(name),
+            "{:?}",
+            pretty,
+            theme,
+            naming,
+            access,
+        );
     }
 
-    let sep = if pretty { "\n" } else { " " };
-    let mut fmt = String::new();
-    let mut args = Vec::new();
-
-    for (i, f) in fields.iter().enumerate() {
-        let fname = f.name();
-        fmt.push_str(&f.display_name()?);
-        fmt.push_str("={}");
-
-        if i + 1 < fields.len() {
-            fmt.push_str(sep);
-        }
-        args.push(quote! { self.#fname });
-    }
+    let formatter = formatter();
+    let builder = local("__builder");
+    let entries = fields
+        .iter()
+        .map(|f| {
+            let fval = access.value(f);
+            let field = match is_named {
+                true => {
+                    let fname_str = naming.name(f)?;
+                    quote! { #builder.field(#fname_str, &#fval); }
+                }
+                false => quote! { #builder.field(&#fval); },
+            };
 
-    Ok(quote! { ::std::write!(f, #fmt, #(#args),*) })
+            Ok(field)
+        })
+        .combine()?;
+
+    let start = match is_named {
+        true => quote!(#formatter.debug_struct(#name)),
+        false => quote!(#formatter.debug_tuple(#name)),
+    };
+
+    Ok(quote! {
+        let mut #builder = #start;
+        #(#entries)*
+        #builder.finish()
+    })
 }
 
-fn render_map(fields: &[&Field], pretty: bool, theme: Option<&str>) -> syn::Result<TokenStream> {
-    let _ = &theme;
-    #[cfg(feature = "color")]
-    if let Some(t) = theme {
-        let (_nc, fc, vc, pc) = themed_tokens(t);
-        let mut fmt = String::new();
-        let mut args: Vec<TokenStream> = Vec::new();
-
-        fmt.push_str("{}");
-        args.push(if pretty {
-            quote! { "{\n" #pc }
-        } else {
-            quote! { "{ " #pc }
-        });
+/// `Name { field: value }`, `Name(value)`, or `{ field: value }`
+/// without a name, each value formatted with `spec`
+#[allow(clippy::too_many_arguments)]
+fn render_bracketed(
+    fields: &[&Field],
+    is_named: bool,
+    name: Option<&str>,
+    spec: &str,
+    pretty: bool,
+    theme: Option<&str>,
+    naming: Naming,
+    access: Access,
+) -> syn::Result<TokenStream> {
+    let paint = Paint::new(theme);
+    let (open, separator, close) = match (is_named, pretty) {
+        (true, false) => ("{ ", ", ", " }"),
+        (true, true) => ("{\n", "", "}"),
+        (false, false) => ("(", ", ", ")"),
+        (false, true) => ("(\n", "", ")"),
+    };
+
+    let open = match name {
+        Some(name) if is_named => {
+            paint.punct(paint.name(Piece::default(), name), &format!(" {open}"))
+        }
+        Some(name) => paint.punct(paint.name(Piece::default(), name), open),
+        None => paint.punct(Piece::default(), open),
+    };
 
-        for (i, f) in fields.iter().enumerate() {
-            let fname = f.name();
-            let dname = f.display_name()?;
+    let entries = fields
+        .iter()
+        .map(|f| {
+            let mut piece = Piece::default();
 
             if pretty {
-                fmt.push_str("    {}{}{}{}");
-                args.push(quote! { #dname #fc });
-                args.push(quote! { ": " #pc });
-                args.push(quote! { ::std::format!("{}", self.#fname) #vc });
-                args.push(quote! { ",\n" #pc });
-            } else {
-                fmt.push_str("{}{}{}");
-                args.push(quote! { #dname #fc });
-                args.push(quote! { ": " #pc });
-                args.push(quote! { ::std::format!("{}", self.#fname) #vc });
-
-                if i + 1 < fields.len() {
-                    fmt.push_str("{}");
-                    args.push(quote! { ", " #pc });
-                }
+                piece = piece.text("    ");
             }
-        }
 
-        fmt.push_str("{}");
-        args.push(if pretty {
-            quote! { "}" #pc }
-        } else {
-            quote! { " }" #pc }
-        });
+            if is_named {
+                piece = paint.punct(paint.field(piece, &naming.name(f)?), ": ");
+            }
 
-        return Ok(quote! { ::std::write!(f, #fmt, #(#args),*) });
-    }
+            piece = paint.value(piece, spec, access.value(f));
 
-    let mut fmt = String::new();
-    let mut args = Vec::new();
+            if pretty {
+                piece = paint.punct(piece, ",\n");
+            }
 
-    fmt.push_str(if pretty { "{{\n" } else { "{{ " });
+            Ok(Entry::new(f, piece, naming, access))
+        })
+        .combine()?;
 
-    for (i, f) in fields.iter().enumerate() {
-        let fname = f.name();
+    Ok(Layout {
+        open,
+        entries,
+        separator: paint.punct(Piece::default(), separator),
+        close: paint.punct(Piece::default(), close),
+    }
+    .render())
+}
 
-        if pretty {
-            fmt.push_str("    ");
-            fmt.push_str(&f.display_name()?);
-            fmt.push_str(": {},\n");
-        } else {
-            fmt.push_str(&f.display_name()?);
-            fmt.push_str(": {}");
+fn render_compact(fields: &[&Field], access: Access) -> TokenStream {
+    Layout {
+        open: Piece::default(),
+        entries: fields
+            .iter()
+            .map(|f| {
+                let piece = Piece::default().arg("{}", access.value(f));
+                Entry::new(f, piece, Naming::default(), access)
+            })
+            .collect(),
+        separator: Piece::default().text(" "),
+        close: Piece::default(),
+    }
+    .render()
+}
 
-            if i + 1 < fields.len() {
-                fmt.push_str(", ");
-            }
-        }
+fn render_keyvalue(
+    fields: &[&Field],
+    pretty: bool,
+    theme: Option<&str>,
+    naming: Naming,
+    access: Access,
+) -> syn::Result<TokenStream> {
+    let paint = Paint::new(theme);
+    let entries = fields
+        .iter()
+        .map(|f| {
+            let piece = paint.punct(paint.field(Piece::default(), &naming.name(f)?), "=");
+            let piece = paint.value(piece, "{}", access.value(f));
+            Ok(Entry::new(f, piece, naming, access))
+        })
+        .combine()?;
 
-        args.push(quote! { self.#fname });
+    Ok(Layout {
+        open: Piece::default(),
+        entries,
+        separator: Piece::default().text(if pretty { "\n" } else { " " }),
+        close: Piece::default(),
     }
+    .render())
+}
 
-    fmt.push_str(if pretty { "}}" } else { " }}" });
-
-    Ok(quote! { ::std::write!(f, #fmt, #(#args),*) })
+fn render_map(
+    fields: &[&Field],
+    pretty: bool,
+    theme: Option<&str>,
+    naming: Naming,
+    access: Access,
+) -> syn::Result<TokenStream> {
+    render_bracketed(fields, true, None, "{}", pretty, theme, naming, access)
 }
 
 fn render_custom_fmt(
@@ -600,116 +644,180 @@ fn render_custom_fmt(
     is_named: bool,
     pattern: &syn::LitStr,
     exprs: &[&syn::Expr],
+    access: Access,
 ) -> TokenStream {
+    let formatter = formatter();
+
     if is_named {
         let field_idents: Vec<_> = fields
             .iter()
             .filter_map(|f| match f.name() {
-                FieldName::Ident(id) => Some(id.clone()),
+                FieldName::Ident(id) => Some(quote!(#id)),
                 _ => None,
             })
             .collect();
 
-        if exprs.is_empty() {
+        // match arm patterns already bind every field by name
+        let destructure = if access == Access::Field {
             quote! {
                 #[allow(unused)]
                 let Self { #(#field_idents,)* .. } = self;
-                ::std::write!(f, #pattern)
+            }
+        } else {
+            quote!()
+        };
+
+        // redacted and formatted fields are shadowed by the value they display
+        let shadowed = fields
+            .iter()
+            .filter(|f| {
+                matches!(Redact::parse(f), Ok(Some(_))) || matches!(Format::parse(f), Ok(Some(_)))
+            })
+            .map(|f| {
+                let binding = Access::binding(f);
+                let value = access.value(f);
+                quote! {
+                    #[allow(unused)]
+                    let #binding = #value;
+                }
+            });
+
+        let bindings = quote! {
+            #destructure
+            #(#shadowed)*
+        };
+
+        if exprs.is_empty() {
+            quote! {
+                #bindings
+                ::core::write!(#formatter, #pattern)
             }
         } else {
             quote! {
-                #[allow(unused)]
-                let Self { #(#field_idents,)* .. } = self;
-                ::std::write!(f, #pattern, #(#exprs),*)
+                #bindings
+                ::core::write!(#formatter, #pattern, #(#exprs),*)
             }
         }
     } else {
         if exprs.is_empty() {
-            let field_indices: Vec<_> = fields.iter().map(|f| f.name().clone()).collect();
+            let field_values: Vec<_> = fields.iter().map(|f| access.value(f)).collect();
 
             quote! {
-                ::std::write!(f, #pattern, #(self.#field_indices,)*)
+                ::core::write!(#formatter, #pattern, #(#field_values,)*)
             }
         } else {
             quote! {
-                ::std::write!(f, #pattern, #(#exprs),*)
+                ::core::write!(#formatter, #pattern, #(#exprs),*)
             }
         }
     }
 }
 
 #[cfg(feature = "json")]
-fn render_json(fields: &[&Field], is_named: bool, pretty: bool) -> syn::Result<TokenStream> {
+fn render_json(
+    fields: &[&Field],
+    is_named: bool,
+    pretty: bool,
+    serde_json: &TokenStream,
+    naming: Naming,
+    access: Access,
+) -> syn::Result<TokenStream> {
+    let formatter = formatter();
+    let (map, arr, val) = (local("__map"), local("__arr"), local("__val"));
+
+    let serialize = if pretty {
+        quote! { #serde_json::to_string_pretty(&#val) }
+    } else {
+        quote! { #serde_json::to_string(&#val) }
+    };
+
     if is_named {
         let inserts = fields
             .iter()
             .map(|f| {
-                let fname = f.name();
-                let dname = f.display_name()?;
-                Ok(quote! {
-                    __map.insert(
+                let fval = access.serialized(f);
+                let dname = naming.name(f)?;
+                let insert = quote! {
+                    #map.insert(
                         #dname.into(),
-                        ::serde_json::to_value(&self.#fname).unwrap_or(::serde_json::Value::Null),
+                        #serde_json::to_value(&#fval).unwrap_or(#serde_json::Value::Null),
                     );
-                })
-            })
-            .collect::<syn::Result<Vec<_>>>()?;
+                };
 
-        let serialize = if pretty {
-            quote! { ::serde_json::to_string_pretty(&__val) }
-        } else {
-            quote! { ::serde_json::to_string(&__val) }
-        };
+                Ok(render_gate(render_skip(f, naming, access).as_ref(), insert))
+            })
+            .combine()?;
 
         Ok(quote! {
-            let mut __map = ::serde_json::Map::new();
+            let mut #map = #serde_json::Map::new();
             #(#inserts)*
-            let __val = ::serde_json::Value::Object(__map);
-            ::std::write!(f, "{}", #serialize.unwrap_or_default())
+            let #val = #serde_json::Value::Object(#map);
+            ::core::write!(#formatter, "{}", #serialize.unwrap_or_default())
         })
     } else {
         let pushes: Vec<_> = fields
             .iter()
             .map(|f| {
-                let fname = f.name();
-                quote! {
-                    __arr.push(
-                        ::serde_json::to_value(&self.#fname).unwrap_or(::serde_json::Value::Null),
+                let fval = access.serialized(f);
+                let push = quote! {
+                    #arr.push(
+                        #serde_json::to_value(&#fval).unwrap_or(#serde_json::Value::Null),
                     );
-                }
+                };
+
+                render_gate(render_skip(f, naming, access).as_ref(), push)
             })
             .collect();
 
-        let serialize = if pretty {
-            quote! { ::serde_json::to_string_pretty(&__val) }
-        } else {
-            quote! { ::serde_json::to_string(&__val) }
-        };
-
         Ok(quote! {
-            let mut __arr = ::std::vec::Vec::new();
+            extern crate alloc;
+            let mut #arr = alloc::vec::Vec::new();
             #(#pushes)*
-            let __val = ::serde_json::Value::Array(__arr);
-            ::std::write!(f, "{}", #serialize.unwrap_or_default())
+            let #val = #serde_json::Value::Array(#arr);
+            ::core::write!(#formatter, "{}", #serialize.unwrap_or_default())
         })
     }
 }
 
+/// render one of the named styles; keyvalue, map and json follow
+/// the fields' serde attributes unless `display(ignore_serde)` is set
 fn render_style(
     style: &str,
     fields: &[&Field],
     is_named: bool,
     name: &str,
-    pretty: bool,
-    theme: Option<&str>,
+    options: &Options,
+    access: Access,
 ) -> syn::Result<TokenStream> {
+    let serde = !options.ignore_serde && matches!(style, "keyvalue" | "map" | "json");
+    let naming = Naming {
+        case: options.case(serde),
+        serde,
+    };
+
+    let fields: Vec<&Field> = fields
+        .iter()
+        .copied()
+        .filter(|f| !(serde && f.serde().skip))
+        .collect();
+
+    let pretty = options.pretty();
+    let theme = options.theme();
+
     match style {
-        "debug" => render_debug(fields, is_named, name, pretty, theme),
-        "compact" => render_compact(fields),
-        "keyvalue" => render_keyvalue(fields, pretty, theme),
-        "map" => render_map(fields, pretty, theme),
+        "debug" => render_debug(&fields, is_named, name, pretty, theme, naming, access),
+        "compact" => Ok(render_compact(&fields, access)),
+        "keyvalue" => render_keyvalue(&fields, pretty, theme, naming, access),
+        "map" => render_map(&fields, pretty, theme, naming, access),
         #[cfg(feature = "json")]
-        "json" => render_json(fields, is_named, pretty),
+        "json" => render_json(
+            &fields,
+            is_named,
+            pretty,
+            &options.runtime("serde_json"),
+            naming,
+            access,
+        ),
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,202 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Config, Field},
+    params,
+};
+
+use super::structs::StructSyntax;
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let ident = &args.input.ident;
+        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let config = Config::load()?;
+        let attributes = Attrs::parse(&args.input.attrs)?;
+        let vis = StructSyntax.render_vis(&attributes, &config)?;
+        let defaults = attributes.defaults("get", &["vis"])?;
+        let variants = args
+            .data
+            .variants
+            .iter()
+            .map(|variant| -> syn::Result<(&syn::Variant, Vec<Field>)> {
+                let fields = match &variant.fields {
+                    syn::Fields::Named(_) => variant
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(i, field)| {
+                            Field::parse(i, field)?.inherit("get", defaults.as_deref())
+                        })
+                        .combine()?,
+                    _ => vec![],
+                };
+
+                Ok((variant, fields))
+            })
+            .combine()?;
+
+        // the first `#[moxy(get)]` occurrence of each field name
+        // decides the getter's name, modifiers and docs
+        let mut getters: Vec<&Field> = vec![];
+
+        for (_, fields) in variants.iter() {
+            for field in fields.iter().filter(|f| f.attrs().enabled("get")) {
+                if !getters.iter().any(|g| g.name() == field.name()) {
+                    getters.push(field);
+                }
+            }
+        }
+
+        let methods = getters
+            .iter()
+            .map(|field| -> syn::Result<TokenStream> {
+                let mut present = vec![];
+                let mut is_shared = true;
+
+                for (variant, fields) in variants.iter() {
+                    let Some(other) = fields.iter().find(|f| f.name() == field.name()) else {
+                        is_shared = false;
+                        continue;
+                    };
+
+                    if other.ty().to_token_stream().to_string()
+                        != field.ty().to_token_stream().to_string()
+                    {
+                        return Err(other.ty().error(&format!(
+                            "field `{}` must have the same type in every variant",
+                            field.name()
+                        )));
+                    }
+
+                    let variant_ident = &variant.ident;
+                    present.push(quote!(Self::#variant_ident));
+                }
+
+                self.render_getter(field, &vis, &present, is_shared)
+            })
+            .combine()?;
+
+        let track = config.track();
+
+        Ok(quote! {
+            impl #impl_generics #ident #type_generics #where_generics {
+                #(#methods)*
+            }
+
+            #track
+        })
+    }
+}
+
+impl EnumSyntax {
+    fn render_getter(
+        &self,
+        field: &Field,
+        vis: &syn::Visibility,
+        present: &[TokenStream],
+        is_shared: bool,
+    ) -> syn::Result<TokenStream> {
+        let fname = field.name();
+        let ty = field.ty();
+        let docs = StructSyntax.render_docs(field);
+        let method_name = match StructSyntax.render_custom_method_name(field)? {
+            Some(id) => id,
+            None => format_ident!("{}", fname.to_string()),
+        };
+        let on_callback = StructSyntax.render_on_callback(field)?;
+        let option_inner = StructSyntax.render_option_inner(field);
+        let is_bool = StructSyntax.render_is_bool(field);
+        let has_copy = StructSyntax.render_has_modifier(field, "copy")?;
+        let has_clone = StructSyntax.render_has_modifier(field, "clone")?;
+        let has_mut = StructSyntax.render_has_modifier(field, "mutable")?;
+
+        let callback = on_callback
+            .as_ref()
+            .map(|expr| quote!(#expr;))
+            .unwrap_or_default();
+
+        let (ret, value) = if let Some(inner_ty) = option_inner {
+            (
+                quote!(::core::option::Option<&<#inner_ty as ::core::ops::Deref>::Target>),
+                quote!(#fname.as_deref()),
+            )
+        } else if is_bool || has_copy {
+            (quote!(#ty), quote!(*#fname))
+        } else if has_clone {
+            (quote!(#ty), quote!(#fname.clone()))
+        } else {
+            (
+                quote!(&<#ty as ::core::ops::Deref>::Target),
+                quote!(::core::ops::Deref::deref(#fname)),
+            )
+        };
+
+        // `Option` fields already return an `Option`, so a field missing
+        // from some variants simply yields `None` there
+        let (ret, value, fallback) = if is_shared {
+            (ret, value, quote!())
+        } else if option_inner.is_some() {
+            (
+                ret,
+                value,
+                quote!(#[allow(unreachable_patterns)] _ => ::core::option::Option::None,),
+            )
+        } else {
+            (
+                quote!(::core::option::Option<#ret>),
+                quote!(::core::option::Option::Some(#value)),
+                quote!(#[allow(unreachable_patterns)] _ => ::core::option::Option::None,),
+            )
+        };
+
+        let getter = quote! {
+            #(#docs)*
+            #vis fn #method_name(&self) -> #ret {
+                #callback
+                match self {
+                    #(#present { #fname, .. } => #value,)*
+                    #fallback
+                }
+            }
+        };
+
+        let mut_getter = if has_mut {
+            let mut_name = format_ident!("{}_mut", fname.to_string());
+
+            if is_shared {
+                quote! {
+                    #vis fn #mut_name(&mut self) -> &mut #ty {
+                        match self {
+                            #(#present { #fname, .. } => #fname,)*
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    #vis fn #mut_name(&mut self) -> ::core::option::Option<&mut #ty> {
+                        match self {
+                            #(#present { #fname, .. } => ::core::option::Option::Some(#fname),)*
+                            #[allow(unreachable_patterns)]
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        Ok(quote! {
+            #getter
+            #mut_getter
+        })
+    }
+}
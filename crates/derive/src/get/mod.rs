@@ -1,11 +1,23 @@
+mod enums;
 mod structs;
 
-use crate::{Render, params::Params};
+use crate::{Render, core::Schema, params::Params};
+
+pub const SCHEMA: Schema = Schema {
+    key: "get",
+    container: Some(&["__flag", "vis =", "copy", "clone", "mutable"]),
+    variant: None,
+    field: Some(&[
+        "__flag", "__value", "skip", "copy", "clone", "mutable", "on =",
+    ]),
+};
 
 pub fn render(tokens: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
     let args = syn::parse(tokens)?;
+    SCHEMA.validate(&args)?;
 
     match args {
         Params::Struct(v) => structs::StructSyntax.render(v),
+        Params::Enum(v) => enums::EnumSyntax.render(v),
     }
 }
@@ -1,7 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::{Render, core::Field, params};
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Config, Field},
+    params,
+};
 
 #[derive(Clone, Default)]
 pub struct StructSyntax;
@@ -12,17 +16,21 @@ impl Render for StructSyntax {
     fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
         let ident = &args.input.ident;
         let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let config = Config::load()?;
+        let attributes = Attrs::parse(&args.input.attrs)?;
+        let vis = self.render_vis(&attributes, &config)?;
+        let defaults = attributes.defaults("get", &["vis"])?;
         let fields: Vec<_> = args
             .data
             .fields
             .iter()
             .enumerate()
-            .map(|(i, field)| Field::parse(i, field))
-            .collect::<syn::Result<Vec<_>>>()?;
+            .map(|(i, field)| Field::parse(i, field)?.inherit("get", defaults.as_deref()))
+            .combine()?;
 
         let fields: Vec<_> = fields
             .into_iter()
-            .filter(|field| field.attrs().exists("get"))
+            .filter(|field| field.attrs().enabled("get"))
             .collect();
 
         let methods: Vec<TokenStream> = fields
@@ -33,7 +41,7 @@ impl Render for StructSyntax {
                 let docs = self.render_docs(field);
                 let method_name = match self.render_custom_method_name(field)? {
                     Some(id) => id,
-                    None => format_ident!("{}", fname.to_string()),
+                    None => fname.to_ident(),
                 };
                 let on_callback = self.render_on_callback(field)?;
                 let is_option = self.render_option_inner(field).is_some();
@@ -51,7 +59,7 @@ impl Render for StructSyntax {
                     let inner_ty = self.render_option_inner(field).unwrap();
                     quote! {
                         #(#docs)*
-                        pub fn #method_name(&self) -> ::std::option::Option<&<#inner_ty as ::std::ops::Deref>::Target> {
+                        #vis fn #method_name(&self) -> ::core::option::Option<&<#inner_ty as ::core::ops::Deref>::Target> {
                             #callback
                             self.#fname.as_deref()
                         }
@@ -59,7 +67,7 @@ impl Render for StructSyntax {
                 } else if is_bool || has_copy {
                     quote! {
                         #(#docs)*
-                        pub fn #method_name(&self) -> #ty {
+                        #vis fn #method_name(&self) -> #ty {
                             #callback
                             self.#fname
                         }
@@ -67,7 +75,7 @@ impl Render for StructSyntax {
                 } else if has_clone {
                     quote! {
                         #(#docs)*
-                        pub fn #method_name(&self) -> #ty {
+                        #vis fn #method_name(&self) -> #ty {
                             #callback
                             self.#fname.clone()
                         }
@@ -75,7 +83,7 @@ impl Render for StructSyntax {
                 } else {
                     quote! {
                         #(#docs)*
-                        pub fn #method_name(&self) -> &<#ty as ::std::ops::Deref>::Target {
+                        #vis fn #method_name(&self) -> &<#ty as ::core::ops::Deref>::Target {
                             #callback
                             &self.#fname
                         }
@@ -83,9 +91,9 @@ impl Render for StructSyntax {
                 };
 
                 let mut_getter = if has_mut {
-                    let mut_name = format_ident!("{}_mut", fname.to_string());
+                    let mut_name = format_ident!("{}_mut", fname.to_ident());
                     quote! {
-                        pub fn #mut_name(&mut self) -> &mut #ty {
+                        #vis fn #mut_name(&mut self) -> &mut #ty {
                             &mut self.#fname
                         }
                     }
@@ -98,18 +106,49 @@ impl Render for StructSyntax {
                     #mut_getter
                 })
             })
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
+
+        let track = config.track();
 
         Ok(quote! {
             impl #impl_generics #ident #type_generics #where_generics {
                 #(#methods)*
             }
+
+            #track
         })
     }
 }
 
 impl StructSyntax {
-    fn render_option_inner<'a>(&self, field: &'a Field) -> Option<&'a syn::Type> {
+    /// the visibility of every getter, from `#[moxy(get(vis = "..."))]`
+    /// on the type or else `moxy.toml`, and `pub` when neither sets it
+    pub fn render_vis(&self, attrs: &Attrs, config: &Config) -> syn::Result<syn::Visibility> {
+        let get_args = attrs.get("get")?;
+        let vis = get_args
+            .iter()
+            .find_map(|arg| arg.as_attr())
+            .and_then(|attr| attr.args().iter().find(|a| a.path().is_ident("vis")));
+
+        let Some(vis) = vis else {
+            return Ok(config
+                .get
+                .vis
+                .clone()
+                .unwrap_or_else(|| syn::parse_quote!(pub)));
+        };
+
+        match vis.as_lit() {
+            Some(syn::Lit::Str(s)) => s
+                .parse()
+                .map_err(|_| s.error("expected a visibility, e.g. `vis = \"pub(crate)\"`")),
+            _ => Err(vis
+                .path()
+                .error("expected a visibility, e.g. `vis = \"pub(crate)\"`")),
+        }
+    }
+
+    pub fn render_option_inner<'a>(&self, field: &'a Field) -> Option<&'a syn::Type> {
         let syn::Type::Path(type_path) = field.ty() else {
             return None;
         };
@@ -126,11 +165,11 @@ impl StructSyntax {
         Some(inner)
     }
 
-    fn render_is_bool(&self, field: &Field) -> bool {
+    pub fn render_is_bool(&self, field: &Field) -> bool {
         matches!(field.ty(), syn::Type::Path(p) if p.path.is_ident("bool"))
     }
 
-    fn render_has_modifier(&self, field: &Field, name: &str) -> syn::Result<bool> {
+    pub fn render_has_modifier(&self, field: &Field, name: &str) -> syn::Result<bool> {
         let get_args = field.attrs().get("get")?;
         Ok(get_args.iter().any(|arg| {
             arg.as_attr()
@@ -138,25 +177,40 @@ impl StructSyntax {
         }))
     }
 
-    fn render_custom_method_name(&self, field: &Field) -> syn::Result<Option<proc_macro2::Ident>> {
+    pub fn render_custom_method_name(
+        &self,
+        field: &Field,
+    ) -> syn::Result<Option<proc_macro2::Ident>> {
         let get_args = field.attrs().get("get")?;
-        Ok(get_args
+        let name = get_args
             .iter()
             .find_map(|arg| arg.as_attr())
             .and_then(|attr| {
-                attr.args().iter().find_map(|a| {
-                    if !a.path().is_ident("__value") {
-                        return None;
-                    }
-                    a.as_lit().and_then(|lit| match lit {
-                        syn::Lit::Str(s) => Some(format_ident!("{}", s.value())),
+                attr.args()
+                    .iter()
+                    .filter(|a| a.path().is_ident("__value"))
+                    .find_map(|a| match a.as_lit() {
+                        Some(syn::Lit::Str(s)) => Some(s.clone()),
                         _ => None,
                     })
-                })
-            }))
+            });
+
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        syn::parse_str::<syn::Ident>(&name.value())
+            .map(|mut ident| {
+                ident.set_span(name.span());
+                Some(ident)
+            })
+            .map_err(|_| name.error(&format!("`{}` is not a valid getter name", name.value())))
     }
 
-    fn render_on_callback(&self, field: &Field) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    pub fn render_on_callback(
+        &self,
+        field: &Field,
+    ) -> syn::Result<Option<proc_macro2::TokenStream>> {
         let get_args = field.attrs().get("get")?;
         Ok(get_args
             .iter()
@@ -169,7 +223,7 @@ impl StructSyntax {
             }))
     }
 
-    fn render_docs<'a>(&self, field: &'a Field) -> Vec<&'a syn::Attribute> {
+    pub fn render_docs<'a>(&self, field: &'a Field) -> Vec<&'a syn::Attribute> {
         field
             .raw_attrs()
             .iter()
@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{Error, Render, params};
+
+const REPR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let ident = &args.input.ident;
+        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let repr = self.render_repr(&args.input)?;
+
+        if let Some(variant) = args
+            .data
+            .variants
+            .iter()
+            .find(|v| !matches!(v.fields, syn::Fields::Unit))
+        {
+            return Err(variant
+                .fields
+                .error("only fieldless enums are supported; variants cannot have fields"));
+        }
+
+        let variants: Vec<_> = args.data.variants.iter().map(|v| &v.ident).collect();
+        let count = variants.len();
+        let (iter_body, to_repr_body) = if variants.is_empty() {
+            (
+                quote!(Self::VARIANTS.iter().map(|v| match *v {})),
+                quote!(match *self {}),
+            )
+        } else {
+            (
+                quote!(Self::VARIANTS.iter().map(|v| match v {
+                    #(Self::#variants => Self::#variants,)*
+                })),
+                quote!(match self {
+                    #(Self::#variants => Self::#variants as #repr,)*
+                }),
+            )
+        };
+
+        Ok(quote! {
+            impl #impl_generics #ident #type_generics #where_generics {
+                /// Every variant, in declaration order.
+                pub const VARIANTS: &'static [Self] = &[#(Self::#variants,)*];
+
+                /// The number of variants.
+                pub const COUNT: usize = #count;
+
+                /// Iterate over every variant in declaration order.
+                pub fn iter() -> impl ::core::iter::DoubleEndedIterator<Item = Self>
+                       + ::core::iter::ExactSizeIterator {
+                    #iter_body
+                }
+
+                /// The variant whose discriminant equals `value`, if any.
+                pub const fn from_repr(value: #repr) -> ::core::option::Option<Self> {
+                    #(
+                        if value == Self::#variants as #repr {
+                            return ::core::option::Option::Some(Self::#variants);
+                        }
+                    )*
+
+                    ::core::option::Option::None
+                }
+
+                /// This variant's discriminant.
+                pub const fn to_repr(&self) -> #repr {
+                    #to_repr_body
+                }
+            }
+        })
+    }
+}
+
+impl EnumSyntax {
+    /// the integer type from `#[repr(u8)]` and friends,
+    /// falling back to `isize` like rustc does
+    fn render_repr(&self, input: &syn::DeriveInput) -> syn::Result<syn::Ident> {
+        let mut repr = None;
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+            attr.parse_nested_meta(|meta| {
+                if let Some(id) = meta.path.get_ident()
+                    && REPR_TYPES.contains(&id.to_string().as_str())
+                {
+                    repr = Some(id.clone());
+                }
+
+                // skip arguments of non-integer reprs such as `align(8)`
+                if meta.input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    content.parse::<TokenStream>()?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(repr.unwrap_or_else(|| format_ident!("isize")))
+    }
+}
@@ -4,9 +4,13 @@ mod default;
 mod deref;
 mod display;
 mod get;
+mod iter;
+mod model;
 pub(crate) mod params;
 mod set;
+mod string;
 mod traits;
+mod variant;
 
 use proc_macro::TokenStream;
 
@@ -14,7 +18,7 @@ pub(crate) use traits::*;
 
 #[proc_macro_derive(Deref, attributes(moxy))]
 pub fn derive_deref(tokens: TokenStream) -> TokenStream {
-    match deref::render(tokens) {
+    match deref::render(tokens.clone()).and_then(|v| core::expand("Deref", tokens, v)) {
         Err(err) => err.to_compile_error().into(),
         Ok(v) => v.into(),
     }
@@ -22,7 +26,7 @@ pub fn derive_deref(tokens: TokenStream) -> TokenStream {
 
 #[proc_macro_derive(Display, attributes(moxy))]
 pub fn derive_display(tokens: TokenStream) -> TokenStream {
-    match display::render(tokens) {
+    match display::render(tokens.clone()).and_then(|v| core::expand("Display", tokens, v)) {
         Err(err) => err.to_compile_error().into(),
         Ok(v) => v.into(),
     }
@@ -30,7 +34,7 @@ pub fn derive_display(tokens: TokenStream) -> TokenStream {
 
 #[proc_macro_derive(Build, attributes(moxy))]
 pub fn derive_build(tokens: TokenStream) -> TokenStream {
-    match build::render(tokens) {
+    match build::render(tokens.clone()).and_then(|v| core::expand("Build", tokens, v)) {
         Err(err) => err.to_compile_error().into(),
         Ok(v) => v.into(),
     }
@@ -38,7 +42,7 @@ pub fn derive_build(tokens: TokenStream) -> TokenStream {
 
 #[proc_macro_derive(Default, attributes(moxy))]
 pub fn derive_default(tokens: TokenStream) -> TokenStream {
-    match default::render(tokens) {
+    match default::render(tokens.clone()).and_then(|v| core::expand("Default", tokens, v)) {
         Err(err) => err.to_compile_error().into(),
         Ok(v) => v.into(),
     }
@@ -46,7 +50,7 @@ pub fn derive_default(tokens: TokenStream) -> TokenStream {
 
 #[proc_macro_derive(Get, attributes(moxy))]
 pub fn derive_get(tokens: TokenStream) -> TokenStream {
-    match get::render(tokens) {
+    match get::render(tokens.clone()).and_then(|v| core::expand("Get", tokens, v)) {
         Err(err) => err.to_compile_error().into(),
         Ok(v) => v.into(),
     }
@@ -54,7 +58,39 @@ pub fn derive_get(tokens: TokenStream) -> TokenStream {
 
 #[proc_macro_derive(Set, attributes(moxy))]
 pub fn derive_set(tokens: TokenStream) -> TokenStream {
-    match set::render(tokens) {
+    match set::render(tokens.clone()).and_then(|v| core::expand("Set", tokens, v)) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(v) => v.into(),
+    }
+}
+
+#[proc_macro_derive(Iter, attributes(moxy))]
+pub fn derive_iter(tokens: TokenStream) -> TokenStream {
+    match iter::render(tokens.clone()).and_then(|v| core::expand("Iter", tokens, v)) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(v) => v.into(),
+    }
+}
+
+#[proc_macro_derive(Str, attributes(moxy))]
+pub fn derive_str(tokens: TokenStream) -> TokenStream {
+    match string::render(tokens.clone()).and_then(|v| core::expand("Str", tokens, v)) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(v) => v.into(),
+    }
+}
+
+#[proc_macro_derive(Variant, attributes(moxy))]
+pub fn derive_variant(tokens: TokenStream) -> TokenStream {
+    match variant::render(tokens.clone()).and_then(|v| core::expand("Variant", tokens, v)) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(v) => v.into(),
+    }
+}
+
+#[proc_macro_attribute]
+pub fn model(args: TokenStream, tokens: TokenStream) -> TokenStream {
+    match model::render(args, tokens.clone()).and_then(|v| core::expand("model", tokens, v)) {
         Err(err) => err.to_compile_error().into(),
         Ok(v) => v.into(),
     }
@@ -0,0 +1,112 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+
+use crate::{
+    Error,
+    core::{Arg, Attr, Attrs, unknown},
+};
+
+/// each key `#[moxy::model(...)]` accepts and the derive it adds
+const MODELS: &[(&str, &str)] = &[
+    ("build", "Build"),
+    ("default", "Default"),
+    ("deref", "Deref"),
+    ("display", "Display"),
+    ("get", "Get"),
+    ("iter", "Iter"),
+    ("set", "Set"),
+    ("str", "Str"),
+    ("variant", "Variant"),
+];
+
+/// the keys that `all` applies to every field
+const ALL: &[&str] = &["build", "get", "set"];
+
+pub fn render(
+    args: proc_macro::TokenStream,
+    tokens: proc_macro::TokenStream,
+) -> syn::Result<TokenStream> {
+    let args =
+        syn::parse::Parser::parse(Punctuated::<Arg, syn::Token![,]>::parse_terminated, args)?;
+    let mut input: syn::DeriveInput = syn::parse(tokens)?;
+    let keys: Vec<_> = MODELS.iter().map(|(key, _)| *key).collect();
+    let mut derives: Vec<&str> = vec![];
+    let mut container: Vec<Arg> = vec![];
+
+    for arg in &args {
+        let name = arg
+            .path()
+            .get_ident()
+            .map(|i| i.to_string())
+            .unwrap_or_default();
+
+        if name == "crate" {
+            container.push(arg.clone());
+            continue;
+        }
+
+        let Some((key, derive)) = MODELS.iter().find(|(key, _)| *key == name) else {
+            return Err(arg.path().error(&unknown(
+                &format!("unknown moxy derive `{name}`"),
+                &name,
+                &keys,
+            )));
+        };
+
+        if !derives.contains(derive) {
+            derives.push(derive);
+        }
+
+        let attr = match arg {
+            Arg::Flag(_) => continue,
+            Arg::Attr(attr) => attr,
+            _ => {
+                return Err(arg
+                    .path()
+                    .error(&format!("expected `{key}` or `{key}(...)`")));
+            }
+        };
+
+        // `all` is the struct level `#[moxy(get)]` that applies
+        // the derive, and the other arguments, to every field
+        if let Some(all) = attr.get("all") {
+            if !ALL.contains(key) {
+                return Err(all
+                    .path()
+                    .error("`all` is only supported by `build`, `get` and `set`"));
+            }
+
+            container.push(Arg::from_flag(attr.path().clone()));
+        }
+
+        let args: Punctuated<Arg, syn::Token![,]> = attr
+            .args()
+            .iter()
+            .filter(|arg| !arg.path().is_ident("all"))
+            .cloned()
+            .collect();
+
+        if !args.is_empty() {
+            container.push(Arg::from_attr(Attr::new(attr.path().clone(), args)));
+        }
+    }
+
+    if matches!(input.data, syn::Data::Union(_)) {
+        return Err(input.ident.error("unions are not supported"));
+    }
+
+    if !container.is_empty() {
+        input
+            .attrs
+            .push(syn::parse_quote!(#[moxy(#(#container),*)]));
+    }
+
+    let facade = Attrs::parse(&input.attrs)?.facade()?;
+    let derives = derives.iter().map(|derive| format_ident!("{}", derive));
+
+    Ok(quote! {
+        #[derive(#(#facade::#derives),*)]
+        #input
+    })
+}
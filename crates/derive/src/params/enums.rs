@@ -0,0 +1,13 @@
+use crate::params::Params;
+
+#[derive(Clone)]
+pub struct EnumParams {
+    pub input: syn::DeriveInput,
+    pub data: syn::DataEnum,
+}
+
+impl From<Params> for EnumParams {
+    fn from(value: Params) -> Self {
+        value.as_enum().expect("expected enum").clone()
+    }
+}
@@ -1,10 +1,15 @@
+mod enums;
 mod structs;
 
+pub use enums::*;
 pub use structs::*;
 
+use crate::Error;
+
 #[derive(Clone)]
 pub enum Params {
     Struct(structs::StructParams),
+    Enum(enums::EnumParams),
 }
 
 impl Params {
@@ -12,6 +17,7 @@ impl Params {
     pub fn input(&self) -> &syn::DeriveInput {
         match self {
             Self::Struct(v) => &v.input,
+            Self::Enum(v) => &v.input,
         }
     }
 
@@ -20,9 +26,22 @@ impl Params {
         matches!(self, Self::Struct(_))
     }
 
+    #[allow(unused)]
+    pub fn is_enum(&self) -> bool {
+        matches!(self, Self::Enum(_))
+    }
+
     pub fn as_struct(&self) -> Option<&structs::StructParams> {
         match self {
             Self::Struct(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_enum(&self) -> Option<&enums::EnumParams> {
+        match self {
+            Self::Enum(v) => Some(v),
+            _ => None,
         }
     }
 }
@@ -33,13 +52,20 @@ impl From<structs::StructParams> for Params {
     }
 }
 
+impl From<enums::EnumParams> for Params {
+    fn from(value: enums::EnumParams) -> Self {
+        Self::Enum(value)
+    }
+}
+
 impl syn::parse::Parse for Params {
     fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
-        let input = syn::DeriveInput::parse(&tokens)?;
+        let input = syn::DeriveInput::parse(tokens)?;
 
         match input.data.clone() {
             syn::Data::Struct(data) => Ok(structs::StructParams { input, data }.into()),
-            _ => panic!("invalid type"),
+            syn::Data::Enum(data) => Ok(enums::EnumParams { input, data }.into()),
+            syn::Data::Union(_) => Err(input.error("unions are not supported")),
         }
     }
 }
@@ -1,7 +1,12 @@
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::quote;
+use syn::{ext::IdentExt, spanned::Spanned};
 
-use crate::{Render, core::Field, params};
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Config, Field, FieldName, unique_param},
+    params,
+};
 
 #[derive(Clone, Default)]
 pub struct StructSyntax;
@@ -12,19 +17,24 @@ impl Render for StructSyntax {
     fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
         let ident = &args.input.ident;
         let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let config = Config::load()?;
+        let attributes = Attrs::parse(&args.input.attrs)?;
+        let prefix = self.render_prefix(&attributes, &config)?;
+        let defaults = attributes.defaults("set", &["prefix"])?;
         let fields: Vec<_> = args
             .data
             .fields
             .iter()
             .enumerate()
-            .map(|(i, field)| Field::parse(i, field))
-            .collect::<syn::Result<Vec<_>>>()?;
+            .map(|(i, field)| Field::parse(i, field)?.inherit("set", defaults.as_deref()))
+            .combine()?;
 
         let fields: Vec<_> = fields
             .into_iter()
-            .filter(|field| field.attrs().exists("set"))
+            .filter(|field| field.attrs().enabled("set"))
             .collect();
 
+        let value_param = unique_param("V", &args.input.generics, &[]);
         let methods: Vec<TokenStream> = fields
             .iter()
             .map(|field| -> syn::Result<TokenStream> {
@@ -35,7 +45,7 @@ impl Render for StructSyntax {
 
                 let method_name = match self.render_custom_method_name(field)? {
                     Some(id) => id,
-                    None => format_ident!("set_{}", fname.to_string()),
+                    None => self.render_method_name(field, &prefix)?,
                 };
 
                 let setter_ty = if is_option {
@@ -50,7 +60,7 @@ impl Render for StructSyntax {
                     if is_option {
                         quote! {
                             let value: #setter_ty = value.into();
-                            self.#fname = ::std::option::Option::Some(#on_expr);
+                            self.#fname = ::core::option::Option::Some(#on_expr);
                         }
                     } else {
                         quote! {
@@ -60,7 +70,7 @@ impl Render for StructSyntax {
                     }
                 } else if is_option {
                     quote! {
-                        self.#fname = ::std::option::Option::Some(value.into());
+                        self.#fname = ::core::option::Option::Some(value.into());
                     }
                 } else {
                     quote! {
@@ -70,23 +80,87 @@ impl Render for StructSyntax {
 
                 Ok(quote! {
                     #(#docs)*
-                    pub fn #method_name<V: ::std::convert::Into<#setter_ty>>(&mut self, value: V) -> &mut Self {
+                    pub fn #method_name<#value_param: ::core::convert::Into<#setter_ty>>(&mut self, value: #value_param) -> &mut Self {
                         #body
                         self
                     }
                 })
             })
-            .collect::<syn::Result<Vec<_>>>()?;
+            .combine()?;
+
+        let track = config.track();
 
         Ok(quote! {
             impl #impl_generics #ident #type_generics #where_generics {
                 #(#methods)*
             }
+
+            #track
         })
     }
 }
 
 impl StructSyntax {
+    /// the prefix of every setter name, from `#[moxy(set(prefix = "..."))]`
+    /// on the struct or else `moxy.toml`, and `set_` when neither sets it
+    fn render_prefix(&self, attrs: &Attrs, config: &Config) -> syn::Result<String> {
+        let set_args = attrs.get("set")?;
+        let prefix = set_args
+            .iter()
+            .find_map(|arg| arg.as_attr())
+            .and_then(|attr| attr.args().iter().find(|a| a.path().is_ident("prefix")));
+
+        let Some(prefix) = prefix else {
+            return Ok(config
+                .set
+                .prefix
+                .clone()
+                .unwrap_or_else(|| "set_".to_string()));
+        };
+
+        match prefix.as_lit() {
+            Some(syn::Lit::Str(s))
+                if syn::parse_str::<syn::Ident>(&format!("{}x", s.value())).is_ok() =>
+            {
+                Ok(s.value())
+            }
+            Some(lit) => {
+                Err(lit.error("expected the start of an identifier, e.g. `prefix = \"with_\"`"))
+            }
+            None => Err(prefix
+                .path()
+                .error("expected the start of an identifier, e.g. `prefix = \"with_\"`")),
+        }
+    }
+
+    /// the setter name of a field without a custom one, the prefix joined to
+    /// the unraw field name and made raw again when that is a keyword
+    fn render_method_name(&self, field: &Field, prefix: &str) -> syn::Result<syn::Ident> {
+        let name = match field.name() {
+            FieldName::Ident(id) => format!("{prefix}{}", id.unraw()),
+            FieldName::Index(i) => format!("{prefix}{}", i.index),
+        };
+
+        let ident = syn::parse_str::<syn::Ident>(&name).or_else(|err| match field.name() {
+            FieldName::Ident(_) => syn::parse_str::<syn::Ident>(&format!("r#{name}")),
+            FieldName::Index(_) => Err(err),
+        });
+
+        ident
+            .map(|mut ident| {
+                ident.set_span(field.name().span());
+                ident
+            })
+            .map_err(|_| match field.name() {
+                FieldName::Index(_) => field.ty().error(&format!(
+                    "`{name}` is not a valid setter name, tuple fields need a non-empty `prefix`"
+                )),
+                FieldName::Ident(_) => field
+                    .name()
+                    .error(&format!("`{name}` is not a valid setter name")),
+            })
+    }
+
     fn render_option_inner<'a>(&self, field: &'a Field) -> Option<&'a syn::Type> {
         let syn::Type::Path(type_path) = field.ty() else {
             return None;
@@ -106,20 +180,29 @@ impl StructSyntax {
 
     fn render_custom_method_name(&self, field: &Field) -> syn::Result<Option<proc_macro2::Ident>> {
         let set_args = field.attrs().get("set")?;
-        Ok(set_args
+        let name = set_args
             .iter()
             .find_map(|arg| arg.as_attr())
             .and_then(|attr| {
-                attr.args().iter().find_map(|a| {
-                    if !a.path().is_ident("__value") {
-                        return None;
-                    }
-                    a.as_lit().and_then(|lit| match lit {
-                        syn::Lit::Str(s) => Some(format_ident!("{}", s.value())),
+                attr.args()
+                    .iter()
+                    .filter(|a| a.path().is_ident("__value"))
+                    .find_map(|a| match a.as_lit() {
+                        Some(syn::Lit::Str(s)) => Some(s.clone()),
                         _ => None,
                     })
-                })
-            }))
+            });
+
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        syn::parse_str::<syn::Ident>(&name.value())
+            .map(|mut ident| {
+                ident.set_span(name.span());
+                Some(ident)
+            })
+            .map_err(|_| name.error(&format!("`{}` is not a valid setter name", name.value())))
     }
 
     fn render_on_callback(&self, field: &Field) -> syn::Result<Option<proc_macro2::TokenStream>> {
@@ -0,0 +1,197 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    Error, Render,
+    core::{Attrs, Config, to_snake_case},
+    display, params,
+};
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let ident = &args.input.ident;
+        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let error_ident = format_ident!("{}ParseError", ident);
+        let attributes = Attrs::parse(&args.input.attrs)?;
+        let case_insensitive = attributes
+            .get("str")?
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .any(|attr| attr.exists("case_insensitive"));
+
+        let config = Config::load()?;
+        let names = display::variant_names(&args.input, &args.data, &config.display)?;
+        let mut as_str_arms = vec![];
+        let mut parse_arms = vec![];
+        let mut seen: Vec<(String, &syn::Ident)> = vec![];
+
+        for (variant, name) in args.data.variants.iter().zip(names) {
+            let variant_ident = &variant.ident;
+            let attrs = Attrs::parse(&variant.attrs)?;
+
+            as_str_arms.push(quote!(Self::#variant_ident { .. } => #name));
+
+            if !matches!(variant.fields, syn::Fields::Unit) {
+                continue;
+            }
+
+            let mut names = vec![name];
+            names.extend(attrs.aliases("str")?);
+
+            for name in names.iter() {
+                let key = if case_insensitive {
+                    name.to_ascii_lowercase()
+                } else {
+                    name.clone()
+                };
+
+                if let Some((_, other)) = seen.iter().find(|(k, _)| *k == key) {
+                    return Err(variant_ident.error(&format!(
+                        "`{}` is already used by variant `{}`",
+                        name, other
+                    )));
+                }
+
+                seen.push((key, variant_ident));
+            }
+
+            parse_arms.push(if case_insensitive {
+                quote! {
+                    if #(value.eq_ignore_ascii_case(#names))||* {
+                        return ::core::result::Result::Ok(Self::#variant_ident);
+                    }
+                }
+            } else {
+                quote! {
+                    #(#names)|* => return ::core::result::Result::Ok(Self::#variant_ident),
+                }
+            });
+        }
+
+        let parse_body = if case_insensitive {
+            quote! { #(#parse_arms)* }
+        } else {
+            quote! {
+                match value {
+                    #(#parse_arms)*
+                    _ => {}
+                }
+            }
+        };
+
+        let as_str_body = if as_str_arms.is_empty() {
+            quote! { match *self {} }
+        } else {
+            quote! {
+                match self {
+                    #(#as_str_arms,)*
+                }
+            }
+        };
+
+        let (error_def, error_value) = self.render_error(&args.input);
+        let track = config.track();
+
+        Ok(quote! {
+            #error_def
+
+            impl #impl_generics #ident #type_generics #where_generics {
+                pub fn as_str(&self) -> &'static str {
+                    #as_str_body
+                }
+            }
+
+            impl #impl_generics ::core::str::FromStr for #ident #type_generics #where_generics {
+                type Err = #error_ident;
+
+                fn from_str(value: &str) -> ::core::result::Result<Self, #error_ident> {
+                    #parse_body
+
+                    ::core::result::Result::Err(#error_value)
+                }
+            }
+
+            impl #impl_generics ::core::convert::TryFrom<&str> for #ident #type_generics #where_generics {
+                type Error = #error_ident;
+
+                fn try_from(value: &str) -> ::core::result::Result<Self, #error_ident> {
+                    <Self as ::core::str::FromStr>::from_str(value)
+                }
+            }
+
+            #track
+        })
+    }
+}
+
+impl EnumSyntax {
+    /// render the parse error type and the expression that builds it
+    /// from `value`. the rejected string is only kept when `alloc` is
+    /// enabled, through a hidden `extern crate` so `#![no_std]` crates
+    /// need no `extern crate alloc` of their own
+    pub fn render_error(&self, input: &syn::DeriveInput) -> (TokenStream, TokenStream) {
+        let vis = &input.vis;
+        let ident = &input.ident;
+        let error_ident = format_ident!("{}ParseError", ident);
+
+        if cfg!(feature = "alloc") {
+            let alloc = format_ident!("__moxy_alloc_{}", to_snake_case(&error_ident.to_string()));
+            let message = format!("unknown `{}` variant: `{{}}`", ident);
+            let def = quote! {
+                #[doc(hidden)]
+                extern crate alloc as #alloc;
+
+                /// The error returned when a string does not name any variant.
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #vis struct #error_ident {
+                    value: #alloc::string::String,
+                }
+
+                impl #error_ident {
+                    /// The string that failed to parse.
+                    pub fn value(&self) -> &str {
+                        &self.value
+                    }
+                }
+
+                impl ::core::fmt::Display for #error_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::write!(f, #message, self.value)
+                    }
+                }
+
+                impl ::core::error::Error for #error_ident {}
+            };
+
+            let value = quote! {
+                #error_ident {
+                    value: #alloc::string::ToString::to_string(value),
+                }
+            };
+
+            (def, value)
+        } else {
+            let message = format!("unknown `{}` variant", ident);
+            let def = quote! {
+                /// The error returned when a string does not name any variant.
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #vis struct #error_ident(());
+
+                impl ::core::fmt::Display for #error_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(#message)
+                    }
+                }
+
+                impl ::core::error::Error for #error_ident {}
+            };
+
+            (def, quote!(#error_ident(())))
+        }
+    }
+}
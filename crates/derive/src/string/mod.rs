@@ -0,0 +1,20 @@
+mod enums;
+
+use crate::{Error, Render, core::Schema, params::Params};
+
+pub const SCHEMA: Schema = Schema {
+    key: "str",
+    container: Some(&["case_insensitive"]),
+    variant: Some(&["alias ="]),
+    field: None,
+};
+
+pub fn render(tokens: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let args = syn::parse(tokens)?;
+    SCHEMA.validate(&args)?;
+
+    match args {
+        Params::Struct(v) => Err(v.input.error("structs are not supported")),
+        Params::Enum(v) => enums::EnumSyntax.render(v),
+    }
+}
@@ -22,3 +22,29 @@ pub(crate) trait Error: syn::spanned::Spanned {
 }
 
 impl<T: syn::spanned::Spanned> Error for T {}
+
+pub(crate) trait Combine<T>: Iterator<Item = syn::Result<T>> + Sized {
+    /// collect every item, combining all errors into one
+    /// so they are reported together instead of one per compile
+    fn combine(self) -> syn::Result<Vec<T>> {
+        let mut items = vec![];
+        let mut error: Option<syn::Error> = None;
+
+        for item in self {
+            match item {
+                Ok(v) => items.push(v),
+                Err(err) => match &mut error {
+                    Some(e) => e.combine(err),
+                    None => error = Some(err),
+                },
+            }
+        }
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(items),
+        }
+    }
+}
+
+impl<T, I: Iterator<Item = syn::Result<T>>> Combine<T> for I {}
@@ -0,0 +1,257 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    Combine, Error, Render,
+    core::{Attrs, Field, to_snake_case},
+    params,
+};
+
+#[derive(Clone, Default)]
+pub struct EnumSyntax;
+
+impl Render for EnumSyntax {
+    type Args = params::EnumParams;
+
+    fn render(&self, args: Self::Args) -> syn::Result<TokenStream> {
+        let ident = &args.input.ident;
+        let (impl_generics, type_generics, where_generics) = &args.input.generics.split_for_impl();
+        let mut views = vec![];
+        let mut methods = vec![];
+
+        for variant in args.data.variants.iter() {
+            let variant_ident = &variant.ident;
+            let name = match self.render_rename(variant)? {
+                Some(name) => name,
+                None => to_snake_case(&variant_ident.to_string()),
+            };
+
+            let is_ident = format_ident!("is_{}", name);
+            let as_ident = format_ident!("as_{}", name);
+            let as_mut_ident = format_ident!("as_{}_mut", name);
+            let into_ident = format_ident!("into_{}", name);
+            let fields: Vec<_> = variant
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| Field::parse(i, field))
+                .combine()?;
+
+            methods.push(quote! {
+                pub fn #is_ident(&self) -> bool {
+                    ::core::matches!(self, Self::#variant_ident { .. })
+                }
+            });
+
+            if fields.is_empty() {
+                continue;
+            }
+
+            let bindings: Vec<_> = fields
+                .iter()
+                .map(|f| {
+                    let binding = f.name().to_ident();
+                    quote!(#binding)
+                })
+                .collect();
+            let types: Vec<_> = fields.iter().map(|f| f.ty()).collect();
+
+            let (pattern, (ref_ty, ref_value), (mut_ty, mut_value), (owned_ty, owned_value)) =
+                match &variant.fields {
+                    syn::Fields::Named(_) => {
+                        let (view_items, projections) =
+                            self.render_views(&args.input, variant_ident, &fields);
+                        views.push(view_items);
+
+                        (
+                            quote!(Self::#variant_ident { #(#bindings),* }),
+                            projections.0,
+                            projections.1,
+                            projections.2,
+                        )
+                    }
+                    _ if fields.len() == 1 => (
+                        quote!(Self::#variant_ident(#(#bindings),*)),
+                        (quote!(&#(#types)*), quote!(#(#bindings)*)),
+                        (quote!(&mut #(#types)*), quote!(#(#bindings)*)),
+                        (quote!(#(#types)*), quote!(#(#bindings)*)),
+                    ),
+                    _ => (
+                        quote!(Self::#variant_ident(#(#bindings),*)),
+                        (quote!((#(&#types,)*)), quote!((#(#bindings,)*))),
+                        (quote!((#(&mut #types,)*)), quote!((#(#bindings,)*))),
+                        (quote!((#(#types,)*)), quote!((#(#bindings,)*))),
+                    ),
+                };
+
+            methods.push(quote! {
+                pub fn #as_ident(&self) -> ::core::option::Option<#ref_ty> {
+                    match self {
+                        #pattern => ::core::option::Option::Some(#ref_value),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                pub fn #as_mut_ident(&mut self) -> ::core::option::Option<#mut_ty> {
+                    match self {
+                        #pattern => ::core::option::Option::Some(#mut_value),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                pub fn #into_ident(self) -> ::core::result::Result<#owned_ty, Self> {
+                    match self {
+                        #pattern => ::core::result::Result::Ok(#owned_value),
+                        #[allow(unreachable_patterns)]
+                        other => ::core::result::Result::Err(other),
+                    }
+                }
+            });
+        }
+
+        Ok(quote! {
+            #(#views)*
+
+            impl #impl_generics #ident #type_generics #where_generics {
+                #(#methods)*
+            }
+        })
+    }
+}
+
+impl EnumSyntax {
+    /// `#[moxy(variant(rename = "name"))]` replaces the
+    /// `snake_case` variant name used in method names
+    fn render_rename(&self, variant: &syn::Variant) -> syn::Result<Option<String>> {
+        let variant_args = Attrs::parse(&variant.attrs)?.get("variant")?;
+        let Some(arg) = variant_args
+            .iter()
+            .filter_map(|arg| arg.as_attr())
+            .find_map(|attr| attr.get("rename"))
+        else {
+            return Ok(None);
+        };
+
+        let Some(syn::Lit::Str(s)) = arg.as_lit() else {
+            return Err(arg
+                .path()
+                .error("expected a string, e.g. `rename = \"custom\"`"));
+        };
+
+        // the rename is spliced into method names such as `is_<rename>`
+        let name = s.value();
+        if syn::parse_str::<syn::Ident>(&format!("is_{name}")).is_err() {
+            return Err(s.error(&format!(
+                "`{name}` cannot be used in a method name, e.g. `is_{name}`"
+            )));
+        }
+
+        Ok(Some(name))
+    }
+
+    /// render the `<Enum><Variant>Ref`, `<Enum><Variant>Mut` and
+    /// `<Enum><Variant>Owned` view structs for a struct-like variant,
+    /// returning them with the (type, value) projection for each
+    #[allow(clippy::type_complexity)]
+    fn render_views(
+        &self,
+        input: &syn::DeriveInput,
+        variant_ident: &syn::Ident,
+        fields: &[Field],
+    ) -> (
+        TokenStream,
+        (
+            (TokenStream, TokenStream),
+            (TokenStream, TokenStream),
+            (TokenStream, TokenStream),
+        ),
+    ) {
+        let ident = &input.ident;
+        let vis = &input.vis;
+        let generics = &input.generics;
+        let params = &generics.params;
+        let type_param_idents: Vec<_> = generics
+            .params
+            .iter()
+            .map(|param| match param {
+                syn::GenericParam::Lifetime(lt) => {
+                    let lifetime = &lt.lifetime;
+                    quote!(#lifetime)
+                }
+                syn::GenericParam::Type(tp) => {
+                    let ident = &tp.ident;
+                    quote!(#ident)
+                }
+                syn::GenericParam::Const(cp) => {
+                    let ident = &cp.ident;
+                    quote!(#ident)
+                }
+            })
+            .collect();
+        let (_, type_generics, where_generics) = generics.split_for_impl();
+        let ref_ident = format_ident!("{}{}Ref", ident, variant_ident);
+        let mut_ident = format_ident!("{}{}Mut", ident, variant_ident);
+        let owned_ident = format_ident!("{}{}Owned", ident, variant_ident);
+        let names: Vec<_> = fields.iter().map(|f| f.name()).collect();
+        let types: Vec<_> = fields.iter().map(|f| f.ty()).collect();
+        let docs: Vec<Vec<_>> = fields
+            .iter()
+            .map(|f| {
+                f.raw_attrs()
+                    .iter()
+                    .filter(|a| a.path().is_ident("doc"))
+                    .collect()
+            })
+            .collect();
+
+        // type parameters the variant's fields don't mention still need a use
+        let (marker_field, marker_value, owned_marker_field) = if params.is_empty() {
+            (quote!(), quote!(), quote!())
+        } else {
+            (
+                quote!(__marker: ::core::marker::PhantomData<&'__view #ident #type_generics>,),
+                quote!(__marker: ::core::marker::PhantomData,),
+                quote!(__marker: ::core::marker::PhantomData<fn() -> #ident #type_generics>,),
+            )
+        };
+
+        let items = quote! {
+            #vis struct #ref_ident <'__view, #params> #where_generics {
+                #(#(#docs)* pub #names: &'__view #types,)*
+                #marker_field
+            }
+
+            #vis struct #mut_ident <'__view, #params> #where_generics {
+                #(#(#docs)* pub #names: &'__view mut #types,)*
+                #marker_field
+            }
+
+            #vis struct #owned_ident <#params> #where_generics {
+                #(#(#docs)* pub #names: #types,)*
+                #owned_marker_field
+            }
+        };
+
+        let projection = |view: &syn::Ident| quote!(#view { #(#names,)* #marker_value });
+
+        (
+            items,
+            (
+                (
+                    quote!(#ref_ident<'_, #(#type_param_idents),*>),
+                    projection(&ref_ident),
+                ),
+                (
+                    quote!(#mut_ident<'_, #(#type_param_idents),*>),
+                    projection(&mut_ident),
+                ),
+                (
+                    quote!(#owned_ident<#(#type_param_idents),*>),
+                    projection(&owned_ident),
+                ),
+            ),
+        )
+    }
+}
@@ -222,3 +222,371 @@ fn test_constant_default_overridden() {
     let c = Client::new().retries(10u32).build();
     assert_eq!(c.retries, 10);
 }
+
+/// Enum — each struct-like variant gets its own builder.
+#[derive(Build, Debug, PartialEq)]
+pub enum Source {
+    File {
+        #[moxy(build)]
+        path: String,
+        #[moxy(build(default = 0o644u32))]
+        mode: u32,
+    },
+    Http {
+        #[moxy(build)]
+        url: String,
+        #[moxy(build)]
+        timeout: Option<u64>,
+        retries: u32,
+    },
+    Stdin(u8),
+    Null,
+}
+
+#[test]
+fn test_enum_variant_builder() {
+    let s = Source::file().path("config.toml").build();
+    assert_eq!(
+        s,
+        Source::File {
+            path: "config.toml".into(),
+            mode: 0o644,
+        }
+    );
+}
+
+#[test]
+fn test_enum_variant_default_overridden() {
+    let s = Source::file().mode(0o600u32).path("x").build();
+    assert_eq!(
+        s,
+        Source::File {
+            path: "x".into(),
+            mode: 0o600,
+        }
+    );
+}
+
+#[test]
+fn test_enum_variant_option_and_skipped_fields() {
+    let s = Source::http().url("https://example.com").build();
+    assert_eq!(
+        s,
+        Source::Http {
+            url: "https://example.com".into(),
+            timeout: None,
+            retries: 0,
+        }
+    );
+
+    let s = Source::http()
+        .timeout(30u64)
+        .url("https://example.com")
+        .build();
+    assert!(matches!(
+        s,
+        Source::Http {
+            timeout: Some(30),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_enum_variant_builder_naming_convention() {
+    let _: SourceFileBuilder = Source::file();
+    let _: SourceHttpBuilder = Source::http();
+    assert_eq!(Source::Stdin(0), Source::Stdin(0));
+    assert_eq!(Source::Null, Source::Null);
+}
+
+/// Enum variant with a custom constructor name and a type parameter.
+#[derive(Build)]
+pub enum Message<T> {
+    #[moxy(build("text"))]
+    PlainText {
+        #[moxy(build)]
+        body: T,
+    },
+    HttpRequest {
+        #[moxy(build)]
+        method: String,
+    },
+}
+
+/// Generic struct without bounds — `Default` is only required
+/// where skipped fields fall back to it.
+#[derive(Build, Default)]
+pub struct Tagged<T> {
+    #[moxy(build)]
+    pub tag: String,
+    pub value: T,
+}
+
+/// Enum variant whose skipped generic field needs `T: Default`.
+#[derive(Build)]
+pub enum Packet<T> {
+    Data {
+        #[moxy(build)]
+        id: u32,
+        payload: T,
+    },
+}
+
+#[test]
+fn test_inferred_bounds() {
+    let t: Tagged<u8> = Tagged::new().tag("a").build();
+    assert_eq!(t.tag, "a");
+    assert_eq!(t.value, 0);
+
+    let p: Packet<Vec<u8>> = Packet::data().id(1u32).build();
+    assert!(matches!(p, Packet::Data { id: 1, payload } if payload.is_empty()));
+}
+
+#[test]
+fn test_enum_custom_ctor_and_generics() {
+    let m: Message<String> = Message::text().body("hello").build();
+    assert!(matches!(m, Message::PlainText { body } if body == "hello"));
+
+    let m: Message<String> = Message::http_request().method("GET").build();
+    assert!(matches!(m, Message::HttpRequest { method } if method == "GET"));
+}
+
+/// Variants whose constructor would be a keyword get a raw identifier.
+#[derive(Build, Debug, PartialEq)]
+pub enum Token {
+    Type {
+        #[moxy(build)]
+        name: String,
+    },
+    Loop {
+        #[moxy(build)]
+        label: String,
+    },
+}
+
+#[test]
+fn test_enum_keyword_ctor() {
+    let t = Token::r#type().name("u8").build();
+    assert_eq!(t, Token::Type { name: "u8".into() });
+
+    let t = Token::r#loop().label("outer").build();
+    assert_eq!(
+        t,
+        Token::Loop {
+            label: "outer".into()
+        }
+    );
+}
+
+/// Newtype wrapper — the lone field is set through `_0`.
+#[derive(Build, Debug, PartialEq)]
+pub struct Meters(#[moxy(build)] pub f64);
+
+/// Tuple struct with named setters and a skipped field.
+#[derive(Build, Debug, Default, PartialEq)]
+pub struct Address(
+    #[moxy(build("host"))] pub String,
+    #[moxy(build("port", default = 80u16))] pub u16,
+    pub bool,
+);
+
+#[test]
+fn test_tuple_struct() {
+    assert_eq!(Meters::new()._0(1.5).build(), Meters(1.5));
+
+    let addr = Address::new().host("localhost").build();
+    assert_eq!(addr, Address("localhost".into(), 80, false));
+
+    let addr = Address::new().port(443u16).host("example.com").build();
+    assert_eq!(addr, Address("example.com".into(), 443, false));
+}
+
+/// Const generic whose name matches a required field's typestate.
+#[derive(Build, Debug, PartialEq)]
+pub struct Buffer<const N: usize> {
+    #[moxy(build)]
+    pub n: usize,
+    #[moxy(build)]
+    pub data: [u8; N],
+}
+
+/// Type parameter default, a defaulted const and a where clause.
+#[derive(Build, Debug, PartialEq)]
+pub struct Window<'a, T = u8, const SIZE: usize = 2>
+where
+    T: Copy,
+{
+    #[moxy(build)]
+    pub name: &'a str,
+    #[moxy(build)]
+    pub values: [T; SIZE],
+}
+
+#[test]
+fn test_const_generics() {
+    let buf = Buffer::new().n(3usize).data([1u8, 2, 3]).build();
+    assert_eq!(
+        buf,
+        Buffer {
+            n: 3,
+            data: [1, 2, 3]
+        }
+    );
+
+    let window: Window = Window::new().values([1u8, 2]).name("w").build();
+    assert_eq!(window.values, [1, 2]);
+    assert_eq!(window.name, "w");
+
+    let window = Window::<u16, 3>::new()
+        .name("x")
+        .values([1u16, 2, 3])
+        .build();
+    assert_eq!(window.values.len(), 3);
+}
+
+/// Required fields whose typestate would clash with the type's own
+/// parameters, a type parameter named `V` and a field named like the
+/// builder's marker.
+#[allow(non_snake_case)]
+mod shadowing {
+    use moxy_derive::Build;
+
+    #[derive(Build, Debug, PartialEq)]
+    pub struct Shadowing<T, V> {
+        #[moxy(build)]
+        pub t: T,
+        #[moxy(build)]
+        pub T: u8,
+        #[moxy(build)]
+        pub value: V,
+        #[moxy(build)]
+        pub self_: bool,
+        #[moxy(build)]
+        pub __marker: bool,
+    }
+
+    #[test]
+    fn test_hygiene() {
+        let s = Shadowing::new()
+            .t("a")
+            .T(1u8)
+            .value(2u16)
+            .self_(true)
+            .__marker(true)
+            .build();
+
+        assert_eq!(
+            s,
+            Shadowing {
+                t: "a",
+                T: 1,
+                value: 2,
+                self_: true,
+                __marker: true,
+            }
+        );
+    }
+}
+
+/// rustc removes fields that are compiled out before any derive runs,
+/// so derives only ever see the fields that exist.
+mod cfg {
+    use moxy_derive::{Build, Display, Get};
+
+    #[derive(Build, Display, Get)]
+    pub struct Endpoint {
+        #[moxy(build, get)]
+        pub host: String,
+        #[cfg(any())]
+        #[moxy(build, get)]
+        pub cert: String,
+        #[cfg(not(any()))]
+        #[moxy(build, get(copy))]
+        pub port: u16,
+    }
+
+    #[derive(Get)]
+    pub enum Reply {
+        Ok {
+            #[cfg(not(any()))]
+            #[moxy(get(copy))]
+            code: u16,
+        },
+        Err {
+            #[cfg(not(any()))]
+            code: u16,
+        },
+    }
+
+    #[test]
+    fn test_cfg_fields() {
+        let endpoint = Endpoint::new().host("localhost").port(80u16).build();
+        assert_eq!(endpoint.host(), "localhost");
+        assert_eq!(endpoint.port(), 80);
+        assert_eq!(
+            endpoint.to_string(),
+            "Endpoint { host: localhost, port: 80 }"
+        );
+
+        let code: u16 = Reply::Err { code: 500 }.code();
+        assert_eq!(code, 500);
+        assert_eq!(Reply::Ok { code: 200 }.code(), 200);
+    }
+}
+
+/// Raw identifier fields keep their own setter names.
+#[derive(Build, Debug, PartialEq)]
+pub struct Keyword {
+    #[moxy(build)]
+    pub r#type: String,
+    #[moxy(build)]
+    pub r#match: bool,
+}
+
+#[test]
+fn test_raw_identifier_fields() {
+    let k = Keyword::new().r#type("a").r#match(true).build();
+    assert_eq!(
+        k,
+        Keyword {
+            r#type: "a".into(),
+            r#match: true,
+        }
+    );
+}
+
+#[derive(Build, Default)]
+#[moxy(build)]
+struct Upstream {
+    host: String,
+    #[moxy(build(default = 443u16))]
+    port: u16,
+    #[moxy(build(skip))]
+    retries: u8,
+}
+
+#[test]
+fn test_container_build() {
+    let upstream = Upstream::new().host("example.com").build();
+    assert_eq!(upstream.host, "example.com");
+    assert_eq!(upstream.port, 443);
+    assert_eq!(upstream.retries, 0);
+}
+
+#[derive(Build, Debug, PartialEq)]
+#[moxy(build)]
+enum Figure {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+}
+
+#[test]
+fn test_container_enum_build() {
+    let figure = Figure::rect().w(1.0).h(2.0).build();
+    assert_eq!(figure, Figure::Rect { w: 1.0, h: 2.0 });
+    assert_eq!(
+        Figure::circle().radius(3.0).build(),
+        Figure::Circle { radius: 3.0 }
+    );
+}
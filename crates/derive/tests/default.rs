@@ -31,7 +31,7 @@ fn test_all_fields_annotated() {
 
     assert_eq!(s.bind, "0.0.0.0");
     assert_eq!(s.port, 8080);
-    assert_eq!(s.tls, true);
+    assert!(s.tls);
 }
 
 const MAX_RETRIES: u32 = 3;
@@ -79,6 +79,36 @@ fn test_generic_struct() {
     assert_eq!(c.value, String::default());
 }
 
+#[derive(Default)]
+struct Inferred<T, U> {
+    pub value: T,
+    #[moxy(default = None)]
+    pub other: Option<U>,
+}
+
+struct NoDefault;
+
+#[test]
+fn test_inferred_bounds() {
+    let c: Inferred<u8, NoDefault> = Inferred::default();
+
+    assert_eq!(c.value, 0);
+    assert!(c.other.is_none());
+}
+
+#[derive(Default)]
+#[moxy(default(bound = ""))]
+struct Unbounded<T> {
+    pub items: Vec<T>,
+}
+
+#[test]
+fn test_custom_bound() {
+    let c: Unbounded<NoDefault> = Unbounded::default();
+
+    assert!(c.items.is_empty());
+}
+
 #[derive(Default)]
 struct Pair(#[moxy(default = "hello")] String, u32);
 
@@ -95,5 +125,71 @@ struct Marker;
 
 #[test]
 fn test_unit_struct() {
-    let _ = Marker::default();
+    let _: Marker = Default::default();
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Default)]
+enum Level {
+    Debug,
+    #[moxy(default)]
+    Info,
+    Error,
+}
+
+#[test]
+fn test_enum_unit_variant() {
+    assert_eq!(Level::default(), Level::Info);
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Default)]
+enum Source {
+    Stdin,
+    #[moxy(default)]
+    File {
+        #[moxy(default = "config.toml")]
+        path: String,
+        #[moxy(default = 0o644u32)]
+        mode: u32,
+        readonly: bool,
+    },
+}
+
+#[test]
+fn test_enum_struct_variant() {
+    assert_eq!(
+        Source::default(),
+        Source::File {
+            path: "config.toml".into(),
+            mode: 0o644,
+            readonly: false,
+        }
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Default)]
+enum Address {
+    #[moxy(default)]
+    V4(#[moxy(default = [127u8, 0, 0, 1])] [u8; 4], u16),
+    V6(String),
+}
+
+#[test]
+fn test_enum_tuple_variant() {
+    assert_eq!(Address::default(), Address::V4([127, 0, 0, 1], 0));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Default)]
+enum Slot<T: std::default::Default> {
+    Empty,
+    #[moxy(default)]
+    Filled(T),
+}
+
+#[test]
+fn test_enum_generic() {
+    assert_eq!(Slot::<u32>::default(), Slot::Filled(0));
 }
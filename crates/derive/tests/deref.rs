@@ -36,3 +36,18 @@ pub fn test_len() {
         7
     );
 }
+
+#[derive(Deref)]
+#[moxy(deref(bound = "T: Clone"))]
+pub struct Shared<T> {
+    value: T,
+}
+
+#[derive(Deref)]
+pub struct Plain<T>(T);
+
+#[test]
+pub fn test_bounds() {
+    assert_eq!(*Shared { value: 3 }, 3);
+    assert_eq!(Plain("abc").len(), 3);
+}
@@ -145,6 +145,13 @@ pub struct FieldExpr {
     count: i32,
 }
 
+#[derive(Display)]
+#[moxy(display("{} <{}>", name, email))]
+pub struct FieldArgs {
+    name: String,
+    email: String,
+}
+
 #[test]
 fn test_named_default() {
     let v = NamedDefault {
@@ -318,6 +325,34 @@ fn test_map_pretty() {
     );
 }
 
+/// `pretty = false` and `color = false` turn off what a parent enum or
+/// `moxy.toml` turns on, and need no feature to be written
+#[derive(Display)]
+#[moxy(display(pretty))]
+pub enum Opted {
+    Pretty {
+        a: u8,
+        b: u8,
+    },
+    #[moxy(display(pretty = false, color = false))]
+    Plain {
+        a: u8,
+        b: u8,
+    },
+}
+
+#[test]
+fn test_pretty_opt_out() {
+    assert_eq!(
+        Opted::Pretty { a: 1, b: 2 }.to_string(),
+        "Pretty {\n    a: 1,\n    b: 2,\n}"
+    );
+    assert_eq!(
+        Opted::Plain { a: 1, b: 2 }.to_string(),
+        "Plain { a: 1, b: 2 }"
+    );
+}
+
 #[test]
 fn test_aliased_struct() {
     let v = AliasedStruct {
@@ -382,6 +417,16 @@ fn test_field_expr() {
     assert_eq!(v.to_string(), "double: 10");
 }
 
+#[test]
+fn test_field_args() {
+    let v = FieldArgs {
+        name: "John".into(),
+        email: "john@example.com".into(),
+    };
+    println!("{v}");
+    assert_eq!(v.to_string(), "John <john@example.com>");
+}
+
 // Split attribute merging — each pair should behave like the combined single attribute.
 
 #[derive(Display)]
@@ -423,6 +468,496 @@ fn test_split_debug_pretty() {
     );
 }
 
+// Enums — each variant is rendered with its own display options.
+
+#[derive(Display)]
+pub enum Status {
+    Active,
+    #[moxy(display(alias = "on-hold"))]
+    Paused,
+    #[moxy(display(alias = "{pending}"))]
+    Pending,
+    Failed(String, i32),
+    Moved {
+        from: String,
+        to: String,
+    },
+}
+
+#[derive(Display)]
+pub enum CustomVariants {
+    #[moxy(display("not found"))]
+    NotFound,
+    #[moxy(display("invalid value {0} (code {1})"))]
+    Invalid(String, i32),
+    #[moxy(display("{name} is {age} years old"))]
+    Person { name: String, age: i32 },
+}
+
+#[derive(Display)]
+pub enum VariantStyles {
+    #[moxy(display(debug))]
+    Debug { name: String },
+    #[moxy(display(compact))]
+    Compact(String, i32),
+    #[moxy(display(keyvalue))]
+    KeyValue { name: String, age: i32 },
+    #[moxy(display(map, pretty))]
+    Map { name: String },
+}
+
+#[derive(Display)]
+#[moxy(display(keyvalue))]
+pub enum InheritedStyle {
+    Login {
+        user: String,
+    },
+    #[moxy(display(map))]
+    Logout {
+        user: String,
+    },
+}
+
+#[derive(Display)]
+pub enum SkippedVariantFields {
+    Secret(#[moxy(display(skip))] String, i32),
+    Hidden {
+        name: String,
+        #[moxy(display(skip))]
+        password: String,
+    },
+}
+
+#[derive(Display)]
+pub enum GenericEnum<T: std::fmt::Display> {
+    Value(T),
+    Empty,
+}
+
+#[test]
+fn test_enum_unit_variant() {
+    assert_eq!(Status::Active.to_string(), "Active");
+}
+
+#[test]
+fn test_enum_unit_variant_alias() {
+    assert_eq!(Status::Paused.to_string(), "on-hold");
+    assert_eq!(Status::Pending.to_string(), "{pending}");
+}
+
+#[test]
+fn test_enum_tuple_variant() {
+    let v = Status::Failed("timeout".into(), 3);
+    assert_eq!(v.to_string(), "Failed(timeout, 3)");
+}
+
+#[test]
+fn test_enum_struct_variant() {
+    let v = Status::Moved {
+        from: "a".into(),
+        to: "b".into(),
+    };
+    assert_eq!(v.to_string(), "Moved { from: a, to: b }");
+}
+
+#[test]
+fn test_enum_custom_fmt() {
+    assert_eq!(CustomVariants::NotFound.to_string(), "not found");
+    assert_eq!(
+        CustomVariants::Invalid("x".into(), 7).to_string(),
+        "invalid value x (code 7)"
+    );
+    assert_eq!(
+        CustomVariants::Person {
+            name: "John".into(),
+            age: 30
+        }
+        .to_string(),
+        "John is 30 years old"
+    );
+}
+
+#[test]
+fn test_enum_variant_styles() {
+    assert_eq!(
+        VariantStyles::Debug {
+            name: "John".into()
+        }
+        .to_string(),
+        "Debug { name: \"John\" }"
+    );
+    assert_eq!(
+        VariantStyles::Compact("John".into(), 30).to_string(),
+        "John 30"
+    );
+    assert_eq!(
+        VariantStyles::KeyValue {
+            name: "John".into(),
+            age: 30
+        }
+        .to_string(),
+        "name=John age=30"
+    );
+    assert_eq!(
+        VariantStyles::Map {
+            name: "John".into()
+        }
+        .to_string(),
+        "{\n    name: John,\n}"
+    );
+}
+
+#[test]
+fn test_enum_inherited_style() {
+    let login = InheritedStyle::Login {
+        user: "john".into(),
+    };
+    let logout = InheritedStyle::Logout {
+        user: "john".into(),
+    };
+    assert_eq!(login.to_string(), "user=john");
+    assert_eq!(logout.to_string(), "{ user: john }");
+}
+
+#[test]
+fn test_enum_skip() {
+    let secret = SkippedVariantFields::Secret("hidden".into(), 1);
+    let hidden = SkippedVariantFields::Hidden {
+        name: "John".into(),
+        password: "hidden".into(),
+    };
+    assert_eq!(secret.to_string(), "Secret(1)");
+    assert_eq!(hidden.to_string(), "Hidden { name: John }");
+}
+
+#[test]
+fn test_enum_generic() {
+    assert_eq!(GenericEnum::Value(42).to_string(), "Value(42)");
+    assert_eq!(GenericEnum::<i32>::Empty.to_string(), "Empty");
+}
+
+// Bounds — inferred from the displayed fields, or given with `bound`.
+
+#[derive(Display)]
+pub struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+#[derive(Display)]
+#[moxy(display(debug))]
+pub struct DebugPair<T> {
+    a: T,
+    b: T,
+}
+
+#[derive(Display)]
+pub struct Skipped<T, U> {
+    value: T,
+    #[moxy(display(skip))]
+    #[allow(dead_code)]
+    hidden: U,
+}
+
+#[derive(Display)]
+#[moxy(display("<{}>", value))]
+#[moxy(display(bound = "T: std::fmt::Display"))]
+pub struct CustomBound<T> {
+    value: T,
+}
+
+#[derive(Display)]
+pub enum Either<L, R> {
+    Left(L),
+    #[moxy(display(debug))]
+    Right(R),
+}
+
+struct NotDisplay;
+
+#[test]
+fn test_inferred_bounds() {
+    assert_eq!(Pair { a: 1, b: 2 }.to_string(), "Pair { a: 1, b: 2 }");
+    assert_eq!(
+        DebugPair { a: "x", b: "y" }.to_string(),
+        r#"DebugPair { a: "x", b: "y" }"#
+    );
+
+    let v = Skipped {
+        value: 7,
+        hidden: NotDisplay,
+    };
+    assert_eq!(v.to_string(), "Skipped { value: 7 }");
+    assert_eq!(Either::<i32, &str>::Left(1).to_string(), "Left(1)");
+    assert_eq!(Either::<i32, &str>::Right("r").to_string(), r#"Right("r")"#);
+}
+
+#[test]
+fn test_custom_bound() {
+    assert_eq!(CustomBound { value: 3 }.to_string(), "<3>");
+}
+
+#[derive(Display)]
+pub struct Credentials {
+    user: &'static str,
+    #[moxy(display(redact))]
+    password: &'static str,
+    #[moxy(display(redact(partial = 4)))]
+    card: u64,
+    #[moxy(display(redact(mask = "[hidden]")))]
+    token: NotDisplay,
+}
+
+#[derive(Display)]
+#[moxy(display("{user}:{password}"))]
+pub struct Dsn {
+    user: &'static str,
+    #[moxy(display(redact))]
+    password: &'static str,
+}
+
+/// Redacted and formatted fields both show as displayed in a custom format.
+#[derive(Display)]
+#[moxy(display("{user}:{password} at {load}"))]
+pub struct Session {
+    user: &'static str,
+    #[moxy(display(redact))]
+    password: &'static str,
+    #[moxy(display(fmt = "{:.1}%"))]
+    load: f64,
+}
+
+#[derive(Display)]
+pub enum Attempt {
+    #[moxy(display("{key} took {elapsed}"))]
+    Login {
+        #[moxy(display(redact(partial = 2)))]
+        key: &'static str,
+        #[moxy(display(fmt = "{:.2}s"))]
+        elapsed: f64,
+    },
+}
+
+#[derive(Display)]
+pub enum Secret {
+    Key(#[moxy(display(redact(partial = 2, mask = "..")))] &'static str),
+}
+
+#[test]
+fn test_redact() {
+    let v = Credentials {
+        user: "bob",
+        password: "hunter2",
+        card: 4242424242424242,
+        token: NotDisplay,
+    };
+    assert_eq!(
+        v.to_string(),
+        "Credentials { user: bob, password: ****, card: ****4242, token: [hidden] }"
+    );
+    assert_eq!(
+        Dsn {
+            user: "bob",
+            password: "hunter2"
+        }
+        .to_string(),
+        "bob:****"
+    );
+    assert_eq!(Secret::Key("abcdef").to_string(), "Key(..ef)");
+    assert_eq!(
+        Session {
+            user: "bob",
+            password: "hunter2",
+            load: 12.345
+        }
+        .to_string(),
+        "bob:**** at 12.3%"
+    );
+    assert_eq!(
+        Attempt::Login {
+            key: "abcdef",
+            elapsed: 1.5
+        }
+        .to_string(),
+        "****ef took 1.50s"
+    );
+
+    // values no longer than the kept characters stay fully masked
+    assert_eq!(Secret::Key("ef").to_string(), "Key(..)");
+}
+
+#[derive(Display)]
+pub struct Reading {
+    #[moxy(display(fmt = "{:.2}"))]
+    value: f64,
+    #[moxy(display(debug))]
+    samples: Vec<u8>,
+    #[moxy(display(hex))]
+    flags: u32,
+    #[moxy(display(fmt = "{:>3}%"))]
+    load: u8,
+}
+
+#[derive(Display)]
+pub struct Register(#[moxy(display(fmt = "{:#06X}"))] u16);
+
+#[derive(Display)]
+pub enum Sample {
+    Value {
+        #[moxy(display(fmt = "{:.1}"))]
+        value: f32,
+    },
+}
+
+#[test]
+fn test_field_format() {
+    let v = Reading {
+        value: 1.23456,
+        samples: vec![1, 2],
+        flags: 255,
+        load: 7,
+    };
+    assert_eq!(
+        v.to_string(),
+        "Reading { value: 1.23, samples: [1, 2], flags: 0xff, load:   7% }"
+    );
+    assert_eq!(Register(0xbeef).to_string(), "Register(0xBEEF)");
+    assert_eq!(
+        Sample::Value { value: 0.25 }.to_string(),
+        "Value { value: 0.2 }"
+    );
+}
+
+#[test]
+fn test_field_format_styles() {
+    #[derive(Display)]
+    #[moxy(display(keyvalue))]
+    struct KeyValue {
+        #[moxy(display(fmt = "{:.2}"))]
+        price: f64,
+        #[moxy(display(debug))]
+        tags: Vec<&'static str>,
+    }
+
+    #[derive(Display)]
+    #[moxy(display(map))]
+    struct Map {
+        #[moxy(display(hex))]
+        id: u8,
+    }
+
+    #[derive(Display)]
+    #[moxy(display(compact))]
+    struct Compact(#[moxy(display(fmt = "{:.1}"))] f64, &'static str);
+
+    #[derive(Display)]
+    #[moxy(display(pretty))]
+    struct Pretty {
+        #[moxy(display(debug))]
+        items: Vec<u8>,
+    }
+
+    #[derive(Display)]
+    #[moxy(display(debug))]
+    struct Debugged {
+        #[moxy(display(fmt = "{:.1}"))]
+        ratio: f64,
+        name: &'static str,
+    }
+
+    assert_eq!(
+        KeyValue {
+            price: 9.5,
+            tags: vec!["a"]
+        }
+        .to_string(),
+        r#"price=9.50 tags=["a"]"#
+    );
+    assert_eq!(Map { id: 16 }.to_string(), "{ id: 0x10 }");
+    assert_eq!(Compact(1.25, "kg").to_string(), "1.2 kg");
+    assert_eq!(
+        Pretty { items: vec![1] }.to_string(),
+        "Pretty {\n    items: [1],\n}"
+    );
+    assert_eq!(
+        Debugged {
+            ratio: 0.75,
+            name: "x"
+        }
+        .to_string(),
+        r#"Debugged { ratio: 0.8, name: "x" }"#
+    );
+}
+
+#[test]
+fn test_redact_styles() {
+    #[derive(Display)]
+    #[moxy(display(debug))]
+    struct Debugged {
+        user: &'static str,
+        #[moxy(display(redact))]
+        password: &'static str,
+    }
+
+    #[derive(Display)]
+    #[moxy(display(keyvalue))]
+    struct KeyValue {
+        user: &'static str,
+        #[moxy(display(redact(partial = 3)))]
+        password: &'static str,
+    }
+
+    #[derive(Display)]
+    #[moxy(display(map))]
+    struct Map {
+        user: &'static str,
+        #[moxy(display(redact))]
+        password: &'static str,
+    }
+
+    assert_eq!(
+        Debugged {
+            user: "bob",
+            password: "hunter2"
+        }
+        .to_string(),
+        r#"Debugged { user: "bob", password: "****" }"#
+    );
+    assert_eq!(
+        KeyValue {
+            user: "bob",
+            password: "hunter2"
+        }
+        .to_string(),
+        "user=bob password=****er2"
+    );
+    assert_eq!(
+        Map {
+            user: "bob",
+            password: "hunter2"
+        }
+        .to_string(),
+        "{ user: bob, password: **** }"
+    );
+}
+
+/// Raw identifiers are shown without their `r#`.
+#[derive(Display)]
+#[moxy(display(keyvalue, rename_all = "UPPERCASE"))]
+pub struct RawKeyValue {
+    r#type: &'static str,
+    r#match: bool,
+}
+
+#[test]
+fn test_raw_field_names() {
+    let v = RawKeyValue {
+        r#type: "a",
+        r#match: true,
+    };
+    assert_eq!(v.to_string(), "TYPE=a MATCH=true");
+}
+
 #[cfg(feature = "json")]
 mod json {
     use moxy_derive::Display;
@@ -506,6 +1041,115 @@ mod json {
         println!("{v}");
         assert_eq!(v.to_string(), r#"{"full_name":"John"}"#);
     }
+
+    #[derive(Display, serde::Serialize)]
+    #[moxy(display(json))]
+    pub enum JsonEnum {
+        Named { name: String, age: i32 },
+        Tuple(String, i32),
+        Unit,
+    }
+
+    #[test]
+    fn test_json_enum() {
+        let named = JsonEnum::Named {
+            name: "John".into(),
+            age: 30,
+        };
+        assert_eq!(named.to_string(), r#"{"age":30,"name":"John"}"#);
+        assert_eq!(
+            JsonEnum::Tuple("hello".into(), 42).to_string(),
+            r#"["hello",42]"#
+        );
+        assert_eq!(JsonEnum::Unit.to_string(), "Unit");
+    }
+
+    mod framework {
+        pub mod moxy {
+            pub use ::moxy::*;
+
+            pub mod __private {
+                pub use serde;
+                pub use serde_json;
+            }
+        }
+    }
+
+    #[derive(Display, serde::Serialize)]
+    #[moxy(crate = framework::moxy, display(json, fields))]
+    pub struct JsonCratePath {
+        name: String,
+    }
+
+    #[derive(Display)]
+    #[moxy(crate = "framework::moxy", display(json))]
+    pub enum JsonCratePathEnum {
+        Named { name: String },
+    }
+
+    /// the inferred `Serialize` bound goes through the crate path too
+    #[derive(Display, serde::Serialize)]
+    #[moxy(crate = framework::moxy, display(json))]
+    pub struct JsonCratePathGeneric<T> {
+        value: T,
+    }
+
+    #[derive(Display, serde::Serialize)]
+    #[moxy(display(json))]
+    pub struct JsonRedact {
+        user: &'static str,
+        #[moxy(display(redact(partial = 4)))]
+        card: u64,
+    }
+
+    #[test]
+    fn test_json_redact() {
+        let v = JsonRedact {
+            user: "bob",
+            card: 4242424242424242,
+        };
+        assert_eq!(v.to_string(), r#"{"card":"****4242","user":"bob"}"#);
+    }
+
+    #[derive(Display, serde::Serialize)]
+    #[moxy(display(json))]
+    pub struct JsonFormat {
+        #[moxy(display(fmt = "{:.2}"))]
+        price: f64,
+    }
+
+    #[test]
+    fn test_json_format() {
+        assert_eq!(JsonFormat { price: 2.0 }.to_string(), r#"{"price":"2.00"}"#);
+    }
+
+    #[derive(Display, serde::Serialize)]
+    #[moxy(display(json))]
+    pub struct JsonRaw {
+        r#type: &'static str,
+    }
+
+    #[test]
+    fn test_json_raw() {
+        assert_eq!(JsonRaw { r#type: "a" }.to_string(), r#"{"type":"a"}"#);
+    }
+
+    #[test]
+    fn test_json_crate_path() {
+        let v = JsonCratePath {
+            name: "John".into(),
+        };
+        assert_eq!(v.to_string(), r#"{"name":"John"}"#);
+        assert_eq!(<JsonCratePath as framework::moxy::Fields>::FIELDS, ["name"]);
+
+        let v = JsonCratePathEnum::Named {
+            name: "John".into(),
+        };
+        assert_eq!(v.to_string(), r#"{"name":"John"}"#);
+
+        let v = JsonCratePathGeneric { value: 42 };
+        assert_eq!(v.to_string(), r#"{"value":42}"#);
+    }
 }
 
 #[cfg(feature = "color")]
@@ -573,6 +1217,47 @@ mod color {
         email: String,
     }
 
+    #[derive(Display)]
+    #[moxy(display(keyvalue, color))]
+    pub struct ColorRedact {
+        #[moxy(display(redact))]
+        password: String,
+    }
+
+    #[test]
+    fn test_color_redact() {
+        let v = ColorRedact {
+            password: "hunter2".into(),
+        };
+        let expected = format!(
+            "{}{}{}",
+            "password".truecolor(255, 121, 198),
+            "=".truecolor(248, 248, 242),
+            "****".truecolor(241, 250, 140),
+        );
+        assert_eq!(v.to_string(), expected);
+    }
+
+    #[derive(Display)]
+    #[moxy(display(map, color))]
+    pub struct ColorFormat {
+        #[moxy(display(fmt = "{:.1}"))]
+        ratio: f64,
+    }
+
+    #[test]
+    fn test_color_format() {
+        let expected = format!(
+            "{}{}{}{}{}",
+            "{ ".truecolor(248, 248, 242),
+            "ratio".truecolor(255, 121, 198),
+            ": ".truecolor(248, 248, 242),
+            "0.5".truecolor(241, 250, 140),
+            " }".truecolor(248, 248, 242),
+        );
+        assert_eq!(ColorFormat { ratio: 0.5 }.to_string(), expected);
+    }
+
     #[test]
     fn test_color_default() {
         let v = ColorDefault {
@@ -768,4 +1453,317 @@ mod color {
         );
         assert_eq!(v.to_string(), expected);
     }
+
+    #[derive(Display)]
+    pub enum ColorEnum {
+        #[moxy(display(color))]
+        Tuple(String, i32),
+        Plain(String),
+    }
+
+    #[test]
+    fn test_color_enum() {
+        let v = ColorEnum::Tuple("hello".into(), 42);
+        let expected = format!(
+            "{}{}{}{}{}{}",
+            "Tuple".truecolor(139, 233, 253).bold(),
+            "(".truecolor(248, 248, 242),
+            "hello".truecolor(241, 250, 140),
+            ", ".truecolor(248, 248, 242),
+            "42".truecolor(241, 250, 140),
+            ")".truecolor(248, 248, 242),
+        );
+        assert_eq!(v.to_string(), expected);
+        assert_eq!(ColorEnum::Plain("hi".into()).to_string(), "Plain(hi)");
+    }
+
+    mod framework {
+        pub mod moxy {
+            pub use ::moxy::*;
+
+            pub mod __private {
+                pub use colored;
+            }
+        }
+    }
+
+    #[derive(Display)]
+    #[moxy(crate = framework::moxy, display(color, fields))]
+    pub struct ColorCratePath(String);
+
+    #[test]
+    fn test_color_crate_path() {
+        let expected = format!(
+            "{}{}{}{}",
+            "ColorCratePath".truecolor(139, 233, 253).bold(),
+            "(".truecolor(248, 248, 242),
+            "hello".truecolor(241, 250, 140),
+            ")".truecolor(248, 248, 242),
+        );
+        assert_eq!(ColorCratePath("hello".into()).to_string(), expected);
+        assert_eq!(<ColorCratePath as framework::moxy::Fields>::FIELDS, ["0"]);
+    }
+}
+
+/// Fields named like generated locals don't shadow them.
+#[derive(Display)]
+#[moxy(display("{f}/{value}"))]
+pub struct Shadowing {
+    f: u8,
+    value: u8,
+}
+
+#[derive(Display)]
+pub struct ShadowingDefault {
+    f: u8,
+    self_: u8,
+}
+
+#[derive(Display)]
+pub enum ShadowingEnum {
+    #[moxy(display(debug))]
+    Named { f: u8 },
+    #[moxy(display("{f}!"))]
+    Custom { f: u8 },
+}
+
+#[test]
+fn test_hygiene() {
+    assert_eq!(Shadowing { f: 1, value: 2 }.to_string(), "1/2");
+    assert_eq!(
+        ShadowingDefault { f: 1, self_: 2 }.to_string(),
+        "ShadowingDefault { f: 1, self_: 2 }"
+    );
+    assert_eq!(ShadowingEnum::Named { f: 3 }.to_string(), "Named { f: 3 }");
+    assert_eq!(ShadowingEnum::Custom { f: 4 }.to_string(), "4!");
+}
+
+#[derive(Display)]
+#[moxy(display(keyvalue, rename_all = "camelCase"))]
+pub struct Request {
+    request_id: u32,
+    #[moxy(display(alias = "UA"))]
+    user_agent: &'static str,
+}
+
+#[derive(Display)]
+#[moxy(display(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub struct Env {
+    home_dir: &'static str,
+}
+
+#[derive(Display)]
+#[moxy(display(map, rename_all = "kebab-case"))]
+pub enum Event {
+    FileOpened {
+        file_path: &'static str,
+    },
+    #[moxy(display(debug, rename_all = "PascalCase"))]
+    FileClosed {
+        file_path: &'static str,
+    },
+}
+
+/// `rename_all` converts field and variant names; `alias` wins.
+#[test]
+fn test_rename_all() {
+    let request = Request {
+        request_id: 7,
+        user_agent: "curl",
+    };
+    assert_eq!(request.to_string(), "requestId=7 UA=curl");
+    assert_eq!(
+        Env { home_dir: "/root" }.to_string(),
+        "Env { HOME_DIR: /root }"
+    );
+    assert_eq!(
+        Event::FileOpened { file_path: "a" }.to_string(),
+        "{ file-path: a }"
+    );
+    assert_eq!(
+        Event::FileClosed { file_path: "a" }.to_string(),
+        "FileClosed { FilePath: \"a\" }"
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_rename_all() {
+    #[derive(Display, serde::Serialize)]
+    #[moxy(display(json, rename_all = "camelCase"))]
+    struct Span {
+        trace_id: u64,
+        parent_span_id: u64,
+    }
+
+    assert_eq!(
+        Span {
+            trace_id: 1,
+            parent_span_id: 2
+        }
+        .to_string(),
+        r#"{"parentSpanId":2,"traceId":1}"#
+    );
+}
+
+mod serde_attrs {
+    use moxy_derive::Display;
+    use serde::Serialize;
+
+    fn is_zero(value: &u32) -> bool {
+        *value == 0
+    }
+
+    #[derive(Display, Serialize)]
+    #[moxy(display(keyvalue))]
+    #[serde(rename_all = "camelCase")]
+    pub struct Login {
+        user_name: &'static str,
+        r#type: &'static str,
+        #[serde(rename = "ip")]
+        remote_addr: &'static str,
+        #[serde(skip)]
+        #[allow(unused)]
+        password: &'static str,
+        #[serde(skip_serializing_if = "is_zero")]
+        retries: u32,
+    }
+
+    #[derive(Display, Serialize)]
+    #[moxy(display(map, ignore_serde))]
+    #[serde(rename_all = "camelCase")]
+    pub struct Raw {
+        user_name: &'static str,
+        #[serde(skip)]
+        token: &'static str,
+    }
+
+    #[derive(Display, Serialize)]
+    #[moxy(display(keyvalue))]
+    #[serde(rename_all_fields = "kebab-case")]
+    pub enum Audit {
+        Deleted {
+            object_id: u32,
+            #[serde(rename = "by")]
+            deleted_by: &'static str,
+        },
+    }
+
+    fn is_empty(value: &&str) -> bool {
+        value.is_empty()
+    }
+
+    #[derive(Display, Serialize)]
+    #[moxy(display(keyvalue))]
+    pub struct Sparse {
+        #[serde(skip_serializing_if = "is_zero")]
+        a: u32,
+        #[serde(skip_serializing_if = "is_zero")]
+        b: u32,
+        #[serde(skip_serializing_if = "is_empty")]
+        c: &'static str,
+        #[serde(skip_serializing_if = "is_zero")]
+        d: u32,
+        #[serde(skip_serializing_if = "is_zero")]
+        e: u32,
+    }
+
+    /// every field may be skipped, checked one by one when displayed
+    #[test]
+    fn test_many_skip_if() {
+        let mut v = Sparse {
+            a: 0,
+            b: 2,
+            c: "",
+            d: 0,
+            e: 5,
+        };
+        assert_eq!(v.to_string(), "b=2 e=5");
+
+        v.a = 1;
+        v.c = "c";
+        assert_eq!(v.to_string(), "a=1 b=2 c=c e=5");
+
+        v = Sparse {
+            a: 0,
+            b: 0,
+            c: "",
+            d: 0,
+            e: 0,
+        };
+        assert_eq!(v.to_string(), "");
+    }
+
+    /// keyvalue, map and json follow serde's naming and skipping;
+    /// `display(alias)` and `display(rename_all)` still win.
+    #[test]
+    fn test_serde_attrs() {
+        let mut login = Login {
+            user_name: "bob",
+            r#type: "admin",
+            remote_addr: "10.0.0.1",
+            password: "hunter2",
+            retries: 0,
+        };
+        assert_eq!(login.to_string(), "userName=bob type=admin ip=10.0.0.1");
+
+        login.retries = 3;
+        assert_eq!(
+            login.to_string(),
+            "userName=bob type=admin ip=10.0.0.1 retries=3"
+        );
+
+        let raw = Raw {
+            user_name: "bob",
+            token: "t",
+        };
+        assert_eq!(raw.to_string(), "{ user_name: bob, token: t }");
+
+        let audit = Audit::Deleted {
+            object_id: 1,
+            deleted_by: "ann",
+        };
+        assert_eq!(audit.to_string(), "object-id=1 by=ann");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_serde_attrs_json() {
+        #[derive(Display, Serialize)]
+        #[moxy(display(json))]
+        #[serde(rename_all = "camelCase")]
+        struct Event {
+            event_id: u32,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            parent_id: Option<u32>,
+            #[serde(rename(serialize = "kind"))]
+            event_kind: &'static str,
+            r#type: &'static str,
+        }
+
+        let mut event = Event {
+            event_id: 1,
+            parent_id: None,
+            event_kind: "click",
+            r#type: "ui",
+        };
+        assert_eq!(
+            event.to_string(),
+            r#"{"eventId":1,"kind":"click","type":"ui"}"#
+        );
+        assert_eq!(
+            event.to_string(),
+            serde_json::to_value(&event).unwrap().to_string()
+        );
+
+        event.parent_id = Some(2);
+        assert_eq!(
+            event.to_string(),
+            r#"{"eventId":1,"kind":"click","parentId":2,"type":"ui"}"#
+        );
+        assert_eq!(
+            event.to_string(),
+            serde_json::to_value(&event).unwrap().to_string()
+        );
+    }
 }
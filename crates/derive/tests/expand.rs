@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use moxy::{Build, Display};
+
+#[derive(Build, Display)]
+#[moxy(debug_expand, display(keyvalue))]
+pub struct Dumped {
+    #[moxy(build)]
+    pub name: String,
+}
+
+#[derive(Display)]
+pub struct NotDumped {
+    pub name: String,
+}
+
+/// where the derives write, resolved the same way they do
+fn expand_dir() -> PathBuf {
+    if let Some(dir) = option_env!("MOXY_EXPAND_DIR") {
+        return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(dir);
+    }
+
+    let target_dir = option_env!("CARGO_TARGET_DIR").map(PathBuf::from);
+    let target_dir = target_dir.unwrap_or_else(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .ancestors()
+            .find(|dir| dir.join("Cargo.lock").is_file())
+            .expect("a Cargo.lock above the crate")
+            .join("target")
+    });
+
+    target_dir.join("moxy-expand")
+}
+
+#[test]
+fn test_debug_expand() {
+    let display = std::fs::read_to_string(expand_dir().join("Dumped.Display.rs")).unwrap();
+    assert!(display.starts_with("// #[moxy] Display for `Dumped`\n"));
+    assert!(display.contains("Display for Dumped"));
+
+    let build = std::fs::read_to_string(expand_dir().join("Dumped.Build.rs")).unwrap();
+    assert!(build.contains("struct DumpedBuilder"));
+}
+
+#[test]
+fn test_no_expand_by_default() {
+    if option_env!("MOXY_EXPAND_DIR").is_none() {
+        assert!(!expand_dir().join("NotDumped.Display.rs").exists());
+    }
+}
@@ -39,8 +39,8 @@ fn test_bool_getter() {
         verified: false,
     };
 
-    assert_eq!(flags.is_active(), true);
-    assert_eq!(flags.verified(), false);
+    assert!(flags.is_active());
+    assert!(!flags.verified());
 }
 
 #[derive(Get)]
@@ -171,3 +171,210 @@ fn test_doc_forwarding() {
     };
     assert_eq!(w.name(), "alice");
 }
+
+#[allow(dead_code)]
+#[derive(Get)]
+enum Event {
+    Created {
+        #[moxy(get)]
+        id: String,
+        #[moxy(get(copy))]
+        at: u64,
+        #[moxy(get)]
+        author: Option<String>,
+    },
+    Renamed {
+        id: String,
+        at: u64,
+        #[moxy(get(mutable))]
+        name: String,
+        author: Option<String>,
+    },
+    Deleted {
+        id: String,
+        at: u64,
+    },
+    Ping(u8),
+}
+
+/// `Ping` has no `id`, so `id` is only present in some variants.
+#[test]
+fn test_enum_getter_missing_from_tuple_variant() {
+    let created = Event::Created {
+        id: "a".into(),
+        at: 1,
+        author: Some("alice".into()),
+    };
+    let deleted = Event::Deleted {
+        id: "b".into(),
+        at: 2,
+    };
+
+    let id: Option<&str> = created.id();
+    assert_eq!(id, Some("a"));
+    assert_eq!(deleted.id(), Some("b"));
+}
+
+#[test]
+fn test_enum_partial_getter() {
+    let deleted = Event::Deleted {
+        id: "b".into(),
+        at: 2,
+    };
+    let ping = Event::Ping(0);
+
+    assert_eq!(deleted.at(), Some(2));
+    assert_eq!(ping.at(), None);
+    assert_eq!(ping.id(), None);
+}
+
+#[test]
+fn test_enum_partial_option_getter() {
+    let created = Event::Created {
+        id: "a".into(),
+        at: 1,
+        author: Some("alice".into()),
+    };
+    let deleted = Event::Deleted {
+        id: "b".into(),
+        at: 2,
+    };
+
+    let author: Option<&str> = created.author();
+    assert_eq!(author, Some("alice"));
+    assert_eq!(deleted.author(), None);
+}
+
+#[test]
+fn test_enum_partial_mut_getter() {
+    let mut renamed = Event::Renamed {
+        id: "a".into(),
+        at: 1,
+        name: "old".into(),
+        author: None,
+    };
+
+    if let Some(name) = renamed.name_mut() {
+        *name = "new".into();
+    }
+
+    assert_eq!(renamed.name(), Some("new"));
+    assert_eq!(Event::Ping(0).name(), None);
+}
+
+#[derive(Get)]
+enum Shape {
+    Circle {
+        #[moxy(get(copy))]
+        x: f64,
+        #[moxy(get(mutable))]
+        label: String,
+    },
+    Square {
+        x: f64,
+        label: String,
+    },
+}
+
+/// Fields present in every variant return the value directly.
+#[test]
+fn test_enum_shared_getter() {
+    let mut shape = Shape::Square {
+        x: 1.5,
+        label: "sq".into(),
+    };
+
+    assert_eq!(shape.x(), 1.5);
+    shape.label_mut().push('!');
+    assert_eq!(shape.label(), "sq!");
+
+    let circle = Shape::Circle {
+        x: 0.5,
+        label: "c".into(),
+    };
+    assert_eq!(circle.x(), 0.5);
+}
+
+#[derive(Get)]
+struct Millis(#[moxy(get(copy, mutable))] u64);
+
+#[derive(Get)]
+struct Named(#[moxy(get("name"))] String, #[moxy(get(copy))] u8);
+
+#[test]
+fn test_tuple_struct_getters() {
+    let mut ms = Millis(5);
+    assert_eq!(ms._0(), 5);
+    *ms._0_mut() += 1;
+    assert_eq!(ms._0(), 6);
+
+    let named = Named("a".into(), 1);
+    assert_eq!(named.name(), "a");
+    assert_eq!(named._1(), 1);
+}
+
+#[derive(Get)]
+struct Shadowing {
+    #[moxy(get(copy))]
+    f: u8,
+    #[moxy(get(copy))]
+    self_: bool,
+}
+
+#[test]
+fn test_hygiene() {
+    let s = Shadowing { f: 1, self_: true };
+    assert_eq!(s.f(), 1);
+    assert!(s.self_());
+}
+
+#[derive(Get)]
+#[moxy(get(copy))]
+struct Point {
+    x: i32,
+    y: i32,
+    #[moxy(get(clone))]
+    label: String,
+    #[moxy(get(skip))]
+    #[allow(dead_code)]
+    hidden: u8,
+}
+
+#[test]
+fn test_container_getters() {
+    let point = Point {
+        x: 1,
+        y: 2,
+        label: "a".into(),
+        hidden: 0,
+    };
+
+    assert_eq!(point.x(), 1);
+    assert_eq!(point.y(), 2);
+    assert_eq!(point.label(), "a".to_string());
+}
+
+#[derive(Get)]
+#[moxy(get)]
+enum Input {
+    Click {
+        #[moxy(get(copy))]
+        x: i32,
+        tag: String,
+    },
+    Key {
+        tag: String,
+    },
+}
+
+#[test]
+fn test_container_enum_getters() {
+    let click = Input::Click {
+        x: 3,
+        tag: "c".into(),
+    };
+
+    assert_eq!(click.x(), Some(3));
+    assert_eq!(click.tag(), "c");
+    assert_eq!(Input::Key { tag: "k".into() }.x(), None);
+}
@@ -0,0 +1,89 @@
+use moxy_derive::Iter;
+
+#[derive(Iter, Debug, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(
+        Direction::VARIANTS,
+        &[
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West
+        ]
+    );
+}
+
+#[test]
+fn test_count() {
+    assert_eq!(Direction::COUNT, 4);
+}
+
+#[test]
+fn test_iter() {
+    let all: Vec<_> = Direction::iter().collect();
+    assert_eq!(all.len(), Direction::COUNT);
+    assert_eq!(all[0], Direction::North);
+    assert_eq!(Direction::iter().next_back(), Some(Direction::West));
+    assert_eq!(Direction::iter().len(), 4);
+}
+
+#[test]
+fn test_from_repr_default_isize() {
+    assert_eq!(Direction::from_repr(0isize), Some(Direction::North));
+    assert_eq!(Direction::from_repr(3), Some(Direction::West));
+    assert_eq!(Direction::from_repr(4), None);
+    assert_eq!(Direction::South.to_repr(), 2isize);
+}
+
+#[derive(Iter, Debug, PartialEq)]
+#[repr(u8)]
+pub enum Code {
+    Ok = 200,
+    Created,
+    NotFound = 40,
+    Teapot = 18 + 100,
+}
+
+#[test]
+fn test_from_repr_explicit_discriminants() {
+    assert_eq!(Code::from_repr(200u8), Some(Code::Ok));
+    assert_eq!(Code::from_repr(201), Some(Code::Created));
+    assert_eq!(Code::from_repr(40), Some(Code::NotFound));
+    assert_eq!(Code::from_repr(118), Some(Code::Teapot));
+    assert_eq!(Code::from_repr(0), None);
+}
+
+#[test]
+fn test_to_repr() {
+    let values: Vec<u8> = Code::iter().map(|c| c.to_repr()).collect();
+    assert_eq!(values, vec![200, 201, 40, 118]);
+}
+
+#[derive(Iter, Debug, PartialEq)]
+#[repr(i16)]
+pub enum Offset {
+    Back = -1,
+    Stay,
+    Forward,
+}
+
+#[test]
+fn test_signed_repr() {
+    assert_eq!(Offset::from_repr(-1i16), Some(Offset::Back));
+    assert_eq!(Offset::from_repr(1), Some(Offset::Forward));
+}
+
+const LOOKUP: Option<Code> = Code::from_repr(40);
+
+#[test]
+fn test_const_from_repr() {
+    assert_eq!(LOOKUP, Some(Code::NotFound));
+}
@@ -0,0 +1,81 @@
+use moxy::Display;
+
+#[moxy::model(display(keyvalue), get(all, clone), set(all), build(all))]
+#[derive(Debug, PartialEq)]
+pub struct User {
+    pub name: String,
+    #[moxy(get(copy), build(default = 18u8))]
+    pub age: u8,
+    #[moxy(get("secret"), display(skip))]
+    pub token: String,
+}
+
+#[moxy::model(display(map), default)]
+#[moxy(display(rename_all = "camelCase"))]
+pub struct Settings {
+    pub max_retries: u32,
+    #[moxy(default = "info")]
+    pub log_level: String,
+}
+
+#[moxy::model(display, get(all, copy), default)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    #[moxy(default)]
+    Point,
+    Circle {
+        radius: u32,
+    },
+}
+
+#[moxy::model(get(all, copy, vis = "pub(crate)"), set(all, prefix = "with_"))]
+pub struct Port {
+    pub number: u16,
+}
+
+// a model can sit next to derives written by hand
+#[moxy::model(get(all))]
+#[derive(Display)]
+pub struct Label(pub String);
+
+#[test]
+fn test_model_struct() {
+    let mut user = User::new().name("john").token("abc").build();
+    assert_eq!(user.age, 18);
+    assert_eq!(user.name(), "john");
+    assert_eq!(user.age(), 18);
+    assert_eq!(user.secret(), "abc");
+    assert_eq!(user.to_string(), "name=john age=18");
+
+    user.set_name("jane").set_age(30u8);
+    assert_eq!(user.name, "jane");
+    assert_eq!(user.age, 30);
+}
+
+#[test]
+fn test_model_merges_container_attrs() {
+    let settings = Settings::default();
+    assert_eq!(settings.to_string(), "{ maxRetries: 0, logLevel: info }");
+}
+
+#[test]
+fn test_model_enum() {
+    assert_eq!(Shape::default(), Shape::Point);
+    assert_eq!(Shape::Circle { radius: 2 }.radius(), Some(2));
+    assert_eq!(Shape::Point.radius(), None);
+    assert_eq!(Shape::Point.to_string(), "Point");
+}
+
+#[test]
+fn test_model_container_args() {
+    let mut port = Port { number: 80 };
+    port.with_number(8080u16);
+    assert_eq!(port.number(), 8080);
+}
+
+#[test]
+fn test_model_tuple() {
+    let label = Label("a".into());
+    assert_eq!(label._0(), "a");
+    assert_eq!(label.to_string(), "Label(a)");
+}
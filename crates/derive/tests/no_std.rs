@@ -0,0 +1,106 @@
+#![no_std]
+
+use core::str::FromStr;
+
+use moxy_derive::{Build, Default, Deref, Display, Get, Iter, Set, Str, Variant};
+
+#[derive(Build, Default, Display, Get, Set)]
+struct Sensor {
+    #[moxy(build, get(copy), set)]
+    id: u32,
+    #[moxy(build(default = 20), get(copy))]
+    threshold: u8,
+    #[moxy(display(skip))]
+    #[allow(dead_code)]
+    raw: Option<u16>,
+}
+
+#[derive(Deref, Display)]
+#[moxy(display(compact))]
+struct Celsius(i16);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Default, Iter, Str, Variant)]
+#[allow(dead_code)]
+enum Mode {
+    #[moxy(default)]
+    Idle,
+    Active,
+    Sleep,
+}
+
+#[derive(Display, Variant)]
+#[allow(dead_code)]
+enum Reading {
+    #[moxy(display(keyvalue))]
+    Temp {
+        value: i16,
+    },
+    Raw(u16),
+}
+
+/// writes into a fixed buffer, since `String` is not available
+struct Buffer {
+    data: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            data: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.data[..self.len]).unwrap()
+    }
+}
+
+impl core::fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.data
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn render(value: &impl core::fmt::Display) -> Buffer {
+    use core::fmt::Write;
+
+    let mut buffer = Buffer::new();
+    write!(buffer, "{value}").unwrap();
+    buffer
+}
+
+#[test]
+fn test_struct_derives() {
+    let mut sensor = Sensor::new().id(7u32).build();
+    assert_eq!(sensor.id(), 7);
+    assert_eq!(sensor.threshold(), 20);
+
+    sensor.set_id(9u32);
+    assert_eq!(render(&sensor).as_str(), "Sensor { id: 9, threshold: 20 }");
+    assert_eq!(*Celsius(21), 21);
+    assert_eq!(render(&Celsius(21)).as_str(), "21");
+}
+
+#[test]
+fn test_enum_derives() {
+    assert_eq!(Mode::default(), Mode::Idle);
+    assert_eq!(Mode::COUNT, 3);
+    assert!(Mode::iter().eq([Mode::Idle, Mode::Active, Mode::Sleep]));
+    assert_eq!(Mode::from_str("Sleep"), Ok(Mode::Sleep));
+    assert!(Mode::from_str("Off").is_err());
+    assert_eq!(Mode::Active.as_str(), "Active");
+    assert!(Mode::Active.is_active());
+    assert_eq!(render(&Mode::Sleep).as_str(), "Sleep");
+
+    let reading = Reading::Temp { value: -4 };
+    assert_eq!(render(&reading).as_str(), "value=-4");
+    assert_eq!(Reading::Raw(3).as_raw(), Some(&3));
+}
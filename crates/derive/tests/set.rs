@@ -119,3 +119,91 @@ fn test_doc_forwarding() {
     w.set_host("localhost");
     assert_eq!(w.host, "localhost");
 }
+
+#[derive(Set)]
+struct Pair(#[moxy(set)] String, #[moxy(set("set_count"))] u8);
+
+#[test]
+fn test_tuple_struct_setters() {
+    let mut pair = Pair(String::new(), 0);
+    pair.set_0("a").set_count(2);
+    assert_eq!(pair.0, "a");
+    assert_eq!(pair.1, 2);
+}
+
+#[derive(Set)]
+struct Shadowing<V> {
+    #[moxy(set(on = value * 2))]
+    value: u8,
+    #[moxy(set)]
+    f: V,
+    #[moxy(set)]
+    self_: bool,
+}
+
+#[test]
+fn test_hygiene() {
+    let mut s = Shadowing {
+        value: 0,
+        f: 0u16,
+        self_: false,
+    };
+    s.set_value(2u8).set_f(3u16).set_self_(true);
+    assert_eq!(s.value, 4);
+    assert_eq!(s.f, 3);
+    assert!(s.self_);
+}
+
+#[derive(Set)]
+#[moxy(set, set(prefix = "with_"))]
+struct Window {
+    title: String,
+    #[moxy(set("resize"))]
+    width: u32,
+    #[moxy(set(skip))]
+    id: u64,
+}
+
+#[test]
+fn test_container_setters() {
+    let mut window = Window {
+        title: String::new(),
+        width: 0,
+        id: 1,
+    };
+
+    window.with_title("main").resize(800u32);
+    assert_eq!(window.title, "main");
+    assert_eq!(window.width, 800);
+    assert_eq!(window.id, 1);
+}
+
+#[derive(Set)]
+#[moxy(set)]
+struct Token {
+    r#type: String,
+    r#match: bool,
+}
+
+#[derive(Set)]
+#[moxy(set, set(prefix = ""))]
+struct Keyword {
+    r#type: String,
+}
+
+#[test]
+fn test_raw_fields() {
+    let mut token = Token {
+        r#type: String::new(),
+        r#match: false,
+    };
+    token.set_type("ident").set_match(true);
+    assert_eq!(token.r#type, "ident");
+    assert!(token.r#match);
+
+    let mut keyword = Keyword {
+        r#type: String::new(),
+    };
+    keyword.r#type("fn");
+    assert_eq!(keyword.r#type, "fn");
+}
@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+use moxy_derive::{Display, Str};
+
+#[derive(Str, Display, Debug, PartialEq)]
+pub enum Level {
+    Debug,
+    #[moxy(display(alias = "info"), str(alias = "information", alias = "i"))]
+    Info,
+    #[moxy(str(alias = "err"))]
+    Error,
+    Custom(u8),
+}
+
+#[test]
+fn test_as_str() {
+    assert_eq!(Level::Debug.as_str(), "Debug");
+    assert_eq!(Level::Info.as_str(), "info");
+    assert_eq!(Level::Custom(1).as_str(), "Custom");
+}
+
+#[test]
+fn test_as_str_matches_display() {
+    assert_eq!(Level::Debug.as_str(), Level::Debug.to_string());
+    assert_eq!(Level::Info.as_str(), Level::Info.to_string());
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(Level::from_str("Debug"), Ok(Level::Debug));
+    assert_eq!("info".parse::<Level>(), Ok(Level::Info));
+    assert_eq!("Error".parse::<Level>(), Ok(Level::Error));
+}
+
+#[test]
+fn test_from_str_aliases() {
+    assert_eq!("information".parse::<Level>(), Ok(Level::Info));
+    assert_eq!("i".parse::<Level>(), Ok(Level::Info));
+    assert_eq!("err".parse::<Level>(), Ok(Level::Error));
+}
+
+#[test]
+fn test_from_str_is_case_sensitive_by_default() {
+    assert!("debug".parse::<Level>().is_err());
+    assert!("Info".parse::<Level>().is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_from_str_error() {
+    let err = "Custom".parse::<Level>().unwrap_err();
+    assert_eq!(err.value(), "Custom");
+    assert_eq!(err.to_string(), "unknown `Level` variant: `Custom`");
+
+    let err: LevelParseError = "nope".parse::<Level>().unwrap_err();
+    let _: &dyn std::error::Error = &err;
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn test_from_str_error_without_alloc() {
+    let err = "Custom".parse::<Level>().unwrap_err();
+    assert_eq!(err.to_string(), "unknown `Level` variant");
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(Level::try_from("Debug"), Ok(Level::Debug));
+    assert!(Level::try_from("nope").is_err());
+}
+
+/// A unit variant's own display format is its name, and
+/// aliases can be spread over several attributes.
+#[derive(Str, Display, Debug, PartialEq)]
+pub enum Outcome {
+    #[moxy(display("not found"))]
+    NotFound,
+    #[moxy(display("{{done}}"))]
+    #[moxy(str(alias = "ok"))]
+    #[moxy(str(alias = "success"))]
+    Done,
+}
+
+#[test]
+fn test_custom_format_round_trip() {
+    assert_eq!(Outcome::NotFound.as_str(), "not found");
+    assert_eq!(Outcome::NotFound.to_string(), "not found");
+    assert_eq!("not found".parse::<Outcome>(), Ok(Outcome::NotFound));
+
+    assert_eq!(Outcome::Done.as_str(), "{done}");
+    assert_eq!(Outcome::Done.to_string(), "{done}");
+    assert_eq!("{done}".parse::<Outcome>(), Ok(Outcome::Done));
+}
+
+#[test]
+fn test_aliases_across_attributes() {
+    assert_eq!("ok".parse::<Outcome>(), Ok(Outcome::Done));
+    assert_eq!("success".parse::<Outcome>(), Ok(Outcome::Done));
+}
+
+#[derive(Str, Debug, PartialEq)]
+#[moxy(str(case_insensitive))]
+pub enum Color {
+    Red,
+    #[moxy(str(alias = "GRN"))]
+    Green,
+}
+
+#[test]
+fn test_case_insensitive() {
+    assert_eq!("red".parse::<Color>(), Ok(Color::Red));
+    assert_eq!("RED".parse::<Color>(), Ok(Color::Red));
+    assert_eq!("grn".parse::<Color>(), Ok(Color::Green));
+    assert!("blue".parse::<Color>().is_err());
+}
+
+#[derive(Str, Display, Debug, PartialEq, Clone, Copy)]
+#[moxy(display(rename_all = "snake_case"))]
+pub enum Service {
+    HttpServer,
+    #[moxy(display(rename_all = "kebab-case"))]
+    DnsResolver,
+    #[moxy(display(alias = "db"))]
+    DatabasePool,
+}
+
+/// `as_str` and `FromStr` use the names Display prints.
+#[test]
+fn test_display_round_trip() {
+    assert_eq!(Service::HttpServer.as_str(), "http_server");
+    assert_eq!(Service::DnsResolver.as_str(), "dns-resolver");
+
+    for service in [
+        Service::HttpServer,
+        Service::DnsResolver,
+        Service::DatabasePool,
+    ] {
+        assert_eq!(service.as_str(), service.to_string());
+        assert_eq!(service.to_string().parse::<Service>(), Ok(service));
+    }
+
+    assert!("HttpServer".parse::<Service>().is_err());
+}
+
+#[derive(Str, Debug, PartialEq)]
+#[moxy(str(case_insensitive))]
+pub enum Temperature {
+    #[moxy(str(alias = "k"))]
+    Kilo,
+    /// the Kelvin sign, which only lowercases to `k` outside ASCII
+    #[moxy(str(alias = "\u{212A}"))]
+    Kelvin,
+}
+
+/// Names only collide when they match ignoring ASCII case, as parsing does.
+#[test]
+fn test_case_insensitive_is_ascii() {
+    assert_eq!("K".parse::<Temperature>(), Ok(Temperature::Kilo));
+    assert_eq!("\u{212A}".parse::<Temperature>(), Ok(Temperature::Kelvin));
+}
@@ -0,0 +1,81 @@
+use moxy::{Build, Buildable, Display, Fields, Style, Styled};
+
+#[derive(Build, Default, Display)]
+#[moxy(build(buildable), display(styled, fields, rename_all = "camelCase"))]
+pub struct Server {
+    #[moxy(build)]
+    pub host_name: String,
+    #[moxy(build(default = 80u16), display(alias = "p"))]
+    pub port: u16,
+}
+
+#[derive(Display)]
+#[moxy(display(styled))]
+pub enum Event {
+    Started { pid: u32 },
+    Stopped,
+}
+
+#[derive(Display)]
+#[moxy(display(fields))]
+pub struct Pair<T>(T, T);
+
+/// generic code can build any `Buildable` type
+fn build_default<T: Buildable<Builder = B>, B>() -> B {
+    T::builder()
+}
+
+#[test]
+fn test_buildable() {
+    let server = build_default::<Server, _>().host_name("localhost").build();
+    assert_eq!(server.host_name, "localhost");
+    assert_eq!(server.port, 80);
+}
+
+#[test]
+fn test_fields() {
+    assert_eq!(Server::FIELDS, ["host_name", "port"]);
+    assert_eq!(Server::ALIASES, ["hostName", "p"]);
+    assert_eq!(Pair::<u8>::FIELDS, ["0", "1"]);
+}
+
+#[test]
+fn test_styled() {
+    let server = Server::new().host_name("a").port(1u16).build();
+    assert_eq!(
+        server.styled(Style::Default).to_string(),
+        "Server { hostName: a, p: 1 }"
+    );
+    assert_eq!(
+        server.styled(Style::Debug).to_string(),
+        "Server { hostName: \"a\", p: 1 }"
+    );
+    assert_eq!(server.styled(Style::Compact).to_string(), "a 1");
+    assert_eq!(server.styled(Style::KeyValue).to_string(), "hostName=a p=1");
+    assert_eq!(
+        server.styled(Style::Map).to_string(),
+        "{ hostName: a, p: 1 }"
+    );
+
+    let event = Event::Started { pid: 7 };
+    assert_eq!(event.styled(Style::KeyValue).to_string(), "pid=7");
+    assert_eq!(event.to_string(), "Started { pid: 7 }");
+    assert_eq!(Event::Stopped.styled(Style::Map).to_string(), "Stopped");
+}
+
+/// `Style` has no JSON variant, so a styled type needn't be `Serialize`
+/// and one derived with `display(json)` renders JSON only through `Display`
+#[cfg(feature = "json")]
+#[test]
+fn test_styled_json() {
+    #[derive(Display, serde::Serialize)]
+    #[moxy(display(json, styled))]
+    struct Job {
+        id: u32,
+    }
+
+    let job = Job { id: 1 };
+    assert_eq!(job.to_string(), r#"{"id":1}"#);
+    assert_eq!(job.styled(Style::Default).to_string(), "Job { id: 1 }");
+    assert_eq!(job.styled(Style::KeyValue).to_string(), "id=1");
+}
@@ -0,0 +1,12 @@
+use moxy_derive::Build;
+
+#[derive(Build)]
+enum Source {
+    #[moxy(build("foo-bar"))]
+    File {
+        #[moxy(build)]
+        path: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Build;
+
+#[derive(Build, Default)]
+struct Config {
+    #[moxy(build(defualt = 8080))]
+    port: u16,
+}
+
+fn main() {}
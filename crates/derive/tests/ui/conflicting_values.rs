@@ -0,0 +1,13 @@
+use moxy_derive::Get;
+
+#[derive(Get)]
+struct User {
+    #[moxy(get("username"))]
+    #[moxy(get("login"))]
+    name: String,
+    #[moxy(get("mail"))]
+    #[moxy(get("address"))]
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+struct User {
+    #[moxy(crate = moxy)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+struct User {
+    #[moxy(debug_expand)]
+    name: String,
+}
+
+fn main() {}
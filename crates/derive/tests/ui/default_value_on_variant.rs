@@ -0,0 +1,10 @@
+use moxy_derive::Default;
+
+#[derive(Default)]
+enum Status {
+    #[moxy(default = 1)]
+    Active,
+    Inactive,
+}
+
+fn main() {}
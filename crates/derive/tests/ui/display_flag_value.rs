@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+struct User {
+    #[moxy(display(skip = 5, alias))]
+    name: String,
+}
+
+fn main() {}
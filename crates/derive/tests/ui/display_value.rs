@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+#[moxy(display = 3)]
+struct User {
+    name: String,
+}
+
+fn main() {}
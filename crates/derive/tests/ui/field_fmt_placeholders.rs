@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+struct Range {
+    #[moxy(display(fmt = "{}..{}"))]
+    start: u32,
+}
+
+fn main() {}
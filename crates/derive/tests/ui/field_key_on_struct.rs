@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+#[moxy(display(skip))]
+struct User {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+#[moxy(display(fields))]
+pub enum Shape {
+    Circle { radius: f64 },
+}
+
+fn main() {}
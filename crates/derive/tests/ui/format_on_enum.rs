@@ -0,0 +1,15 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+#[moxy(display("shape"))]
+pub enum Shape {
+    Circle { radius: f64 },
+}
+
+#[derive(Display)]
+#[moxy(display(alias = "Figure"))]
+pub enum Figure {
+    Square { side: f64 },
+}
+
+fn main() {}
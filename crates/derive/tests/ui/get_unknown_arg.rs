@@ -0,0 +1,9 @@
+use moxy_derive::Get;
+
+#[derive(Get)]
+struct User {
+    #[moxy(get(cone))]
+    name: String,
+}
+
+fn main() {}
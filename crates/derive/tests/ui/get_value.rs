@@ -0,0 +1,9 @@
+use moxy_derive::Get;
+
+#[derive(Get)]
+struct User {
+    #[moxy(get = 5)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::{Build, Get, Set};
+
+#[derive(Build, Get, Set)]
+struct Config {
+    #[moxy(build("with-dash"), get("1st"), set("r#self"))]
+    port: u16,
+}
+
+fn main() {}
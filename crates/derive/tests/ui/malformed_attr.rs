@@ -0,0 +1,9 @@
+use moxy_derive::Set;
+
+#[derive(Set)]
+struct User {
+    #[moxy(set(on = ))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use moxy_derive::Deref;
+
+#[derive(Deref)]
+#[moxy(deref)]
+struct Email(String);
+
+fn main() {}
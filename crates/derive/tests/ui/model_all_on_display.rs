@@ -0,0 +1,6 @@
+#[moxy_derive::model(display(all))]
+struct User {
+    name: String,
+}
+
+fn main() {}
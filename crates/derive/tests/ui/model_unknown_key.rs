@@ -0,0 +1,6 @@
+#[moxy_derive::model(dispaly(json), get(all))]
+struct User {
+    name: String,
+}
+
+fn main() {}
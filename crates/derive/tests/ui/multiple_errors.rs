@@ -0,0 +1,14 @@
+use moxy_derive::{Build, Display};
+
+#[derive(Build, Default, Display)]
+#[moxy(display(pretyy))]
+struct Config {
+    #[moxy(build(defualt = "localhost"))]
+    host: String,
+    #[moxy(build, display(skip, hiden))]
+    port: u16,
+    #[moxy(build(default = 30), display(compact))]
+    timeout: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+struct Card {
+    #[moxy(display(redact(parital = 4)))]
+    number: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use moxy_derive::Set;
+
+#[derive(Set)]
+#[moxy(set(prefix = "1_"))]
+struct Config {
+    #[moxy(set)]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use moxy_derive::Set;
+
+#[derive(Set)]
+#[moxy(set, set(prefix = ""))]
+struct Pair(u16, u16);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use moxy_derive::{Display, Str};
+
+const CODE: u16 = 404;
+
+#[derive(Display, Str)]
+pub enum Status {
+    #[moxy(display("error {}", CODE))]
+    Error,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+struct User {
+    #[moxy(display(compact))]
+    name: String,
+}
+
+fn main() {}
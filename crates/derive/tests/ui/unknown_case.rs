@@ -0,0 +1,9 @@
+use moxy_derive::Display;
+
+#[derive(Display)]
+#[moxy(display(rename_all = "camel"))]
+struct Request {
+    request_id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use moxy_derive::Variant;
+
+#[derive(Variant)]
+enum Shape {
+    #[moxy(variant(rename = "foo-bar"))]
+    Circle,
+}
+
+fn main() {}
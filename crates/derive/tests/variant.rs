@@ -0,0 +1,113 @@
+use moxy_derive::Variant;
+
+#[derive(Variant, Debug, PartialEq)]
+pub enum Shape {
+    Empty,
+    Point(i32, i32),
+    Circle(f64),
+    Rect {
+        width: u32,
+        height: u32,
+    },
+    #[moxy(variant(rename = "label"))]
+    TextLabel(String),
+}
+
+#[test]
+fn test_is_methods() {
+    assert!(Shape::Empty.is_empty());
+    assert!(Shape::Point(1, 2).is_point());
+    assert!(!Shape::Point(1, 2).is_circle());
+    assert!(
+        Shape::Rect {
+            width: 1,
+            height: 2
+        }
+        .is_rect()
+    );
+}
+
+#[test]
+fn test_tuple_projection() {
+    let p = Shape::Point(1, 2);
+    assert_eq!(p.as_point(), Some((&1, &2)));
+    assert_eq!(Shape::Empty.as_point(), None);
+}
+
+#[test]
+fn test_single_field_projection() {
+    let c = Shape::Circle(1.5);
+    assert_eq!(c.as_circle(), Some(&1.5));
+    assert_eq!(c.into_circle(), Ok(1.5));
+}
+
+#[test]
+fn test_tuple_projection_mut() {
+    let mut p = Shape::Point(1, 2);
+
+    if let Some((x, y)) = p.as_point_mut() {
+        *x += 10;
+        *y += 20;
+    }
+
+    assert_eq!(p, Shape::Point(11, 22));
+}
+
+#[test]
+fn test_into_tuple() {
+    assert_eq!(Shape::Point(1, 2).into_point(), Ok((1, 2)));
+    assert_eq!(Shape::Empty.into_point(), Err(Shape::Empty));
+}
+
+#[test]
+fn test_struct_projection() {
+    let mut r = Shape::Rect {
+        width: 3,
+        height: 4,
+    };
+
+    let view: ShapeRectRef = r.as_rect().unwrap();
+    assert_eq!(*view.width, 3);
+    assert_eq!(*view.height, 4);
+
+    let view: ShapeRectMut = r.as_rect_mut().unwrap();
+    *view.width = 30;
+
+    let owned: ShapeRectOwned = r.into_rect().unwrap();
+    assert_eq!(owned.width, 30);
+    assert_eq!(owned.height, 4);
+}
+
+#[test]
+fn test_rename() {
+    let l = Shape::TextLabel("hi".into());
+    assert!(l.is_label());
+    assert_eq!(l.as_label().map(String::as_str), Some("hi"));
+    assert_eq!(l.into_label(), Ok("hi".to_string()));
+}
+
+#[derive(Variant)]
+pub enum Message<'a, T> {
+    Text { body: &'a str },
+    Data(T),
+}
+
+#[test]
+fn test_generics() {
+    let m: Message<u32> = Message::Text { body: "hello" };
+    assert_eq!(m.as_text().map(|v| *v.body), Some("hello"));
+    assert!(m.as_data().is_none());
+
+    let m: Message<u32> = Message::Data(7);
+    assert_eq!(m.into_data().ok(), Some(7));
+}
+
+#[derive(Variant)]
+pub enum Single {
+    Only(u8),
+}
+
+#[test]
+fn test_single_variant() {
+    assert_eq!(Single::Only(1).into_only().ok(), Some(1));
+}
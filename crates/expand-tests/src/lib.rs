@@ -0,0 +1,10 @@
+//! compiled as a dependency of the tests, where cargo sets
+//! neither `OUT_DIR` nor `CARGO_TARGET_TMPDIR` for the derives
+
+use moxy::Display;
+
+#[derive(Display)]
+#[moxy(debug_expand)]
+pub struct Library {
+    pub name: String,
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use moxy_expand_tests::Library;
+
+/// where the derives write for the library, resolved the same way they do
+fn expand_dir() -> PathBuf {
+    if let Some(dir) = option_env!("MOXY_EXPAND_DIR") {
+        return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(dir);
+    }
+
+    let target_dir = option_env!("CARGO_TARGET_DIR").map(PathBuf::from);
+    let target_dir = target_dir.unwrap_or_else(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .ancestors()
+            .find(|dir| dir.join("Cargo.lock").is_file())
+            .expect("a Cargo.lock above the crate")
+            .join("target")
+    });
+
+    target_dir.join("moxy-expand")
+}
+
+#[test]
+fn test_debug_expand_in_library() {
+    let library = Library {
+        name: "moxy".into(),
+    };
+    assert_eq!(library.to_string(), "Library { name: moxy }");
+
+    let display = std::fs::read_to_string(expand_dir().join("Library.Display.rs")).unwrap();
+    assert!(display.starts_with("// #[moxy] Display for `Library`\n"));
+}
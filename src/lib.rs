@@ -1,2 +1,20 @@
+#![no_std]
+
+mod traits;
+
 #[cfg(feature = "derive")]
 pub use moxy_derive::*;
+pub use traits::*;
+
+/// runtime dependencies of the generated code, reached through
+/// `#[moxy(crate = ...)]` so downstream crates don't need
+/// to depend on them directly
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "color")]
+    pub use colored;
+    #[cfg(feature = "json")]
+    pub use serde;
+    #[cfg(feature = "json")]
+    pub use serde_json;
+}
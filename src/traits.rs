@@ -0,0 +1,73 @@
+use core::fmt;
+
+///
+/// ## Buildable
+/// a type with a `#[derive(Build)]` builder,
+/// reachable without naming the builder type
+///
+pub trait Buildable {
+    /// the builder in its initial state, as returned by `new()`
+    type Builder;
+
+    fn builder() -> Self::Builder;
+}
+
+///
+/// ## Fields
+/// the field names of a `#[derive(Display)]` struct,
+/// as declared and as displayed
+///
+pub trait Fields {
+    /// each field's name in declaration order,
+    /// `"0"`, `"1"` and so on for tuple structs
+    const FIELDS: &'static [&'static str];
+
+    /// each field's displayed name, its `alias` or
+    /// its name converted by `rename_all`
+    const ALIASES: &'static [&'static str];
+}
+
+///
+/// ## Style
+/// a display mode a [`Styled`] type can be rendered in,
+/// regardless of the style it was derived with
+///
+/// JSON is not one of them, since rendering it would need every
+/// styled type to implement `Serialize`. a type derived with
+/// `display(json)` still renders JSON through its `Display`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Style {
+    Default,
+    Debug,
+    Compact,
+    KeyValue,
+    Map,
+}
+
+///
+/// ## Styled
+/// a `#[derive(Display)]` type that can be
+/// rendered in any [`Style`]
+///
+pub trait Styled {
+    fn fmt_styled(&self, style: Style, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// a value that displays `self` in `style`
+    fn styled(&self, style: Style) -> WithStyle<'_, Self> {
+        WithStyle { value: self, style }
+    }
+}
+
+/// the [`Display`](fmt::Display) adapter returned by [`Styled::styled`]
+pub struct WithStyle<'a, T: ?Sized> {
+    value: &'a T,
+    style: Style,
+}
+
+impl<T: Styled + ?Sized> fmt::Display for WithStyle<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_styled(self.style, f)
+    }
+}